@@ -0,0 +1,31 @@
+#[macro_use]
+extern crate criterion;
+extern crate verex;
+
+use criterion::{BenchmarkId, Criterion};
+use verex::bench_support::{builder_chain, escape_inputs};
+use verex::{escape, Verex};
+
+fn bench_building(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build_chain");
+    // the time per call has to stay constant, building must not become quadratic again
+    for n in [100, 1000, 10000].iter() {
+        group.bench_with_input(BenchmarkId::from_parameter(n), n, |b, &n| b.iter(|| builder_chain(n).source().len()));
+    }
+    group.finish();
+}
+
+fn bench_tiny_patterns(c: &mut Criterion) {
+    c.bench_function("tiny_patterns", |b| b.iter(|| {
+        (0..1000).map(|_| Verex::with_capacity(32).start_of_line().digit().word().end_of_line().source().len())
+                 .sum::<usize>()
+    }));
+}
+
+fn bench_escaping(c: &mut Criterion) {
+    let inputs = escape_inputs(1000);
+    c.bench_function("escape", |b| b.iter(|| inputs.iter().map(|input| escape(input).len()).sum::<usize>()));
+}
+
+criterion_group!(benches, bench_building, bench_tiny_patterns, bench_escaping);
+criterion_main!(benches);
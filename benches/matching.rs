@@ -0,0 +1,34 @@
+#[macro_use]
+extern crate criterion;
+extern crate regex;
+extern crate verex;
+
+use criterion::{BenchmarkId, Criterion};
+use regex::Regex;
+use verex::bench_support::{presets, sample_text, word_alternation};
+
+fn bench_compiling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("compile");
+    for (name, verex) in presets() {
+        // compile the source directly, `compile()` would return the cached regex
+        group.bench_function(name, |b| b.iter(|| Regex::new(verex.source()).is_ok()));
+    }
+    for n in [10, 100].iter() {
+        let verex = word_alternation(*n);
+        group.bench_with_input(BenchmarkId::new("word_alternation", n), n, |b, _| b.iter(|| Regex::new(verex.source()).is_ok()));
+    }
+    group.finish();
+}
+
+fn bench_matching(c: &mut Criterion) {
+    let text = sample_text(100);
+    let mut group = c.benchmark_group("find_iter");
+    for (name, verex) in presets() {
+        let regex = verex.compile().unwrap();
+        group.bench_function(name, |b| b.iter(|| regex.find_iter(text.as_ref()).count()));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_compiling, bench_matching);
+criterion_main!(benches);
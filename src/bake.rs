@@ -0,0 +1,146 @@
+//! Helpers for baking patterns into a crate from its build script
+//!
+//! A `build.rs` builds the patterns with the builder, `PatternBaker` checks that they compile and
+//! writes them as `static` strings to a file in `OUT_DIR`, which the crate includes. At runtime
+//! only the finished regex strings are left, no builder calls.
+//!
+//! ```no_run
+//! # extern crate verex;
+//! // build.rs
+//! use verex::bake::PatternBaker;
+//! use verex::Verex;
+//!
+//! fn main() {
+//!     PatternBaker::new()
+//!         .add("VERSION", Verex::new().digit().repeat_once_or_more().then(".").digit()).unwrap()
+//!         .write_to_out_dir("patterns.rs").unwrap();
+//! }
+//!
+//! // src/lib.rs:
+//! // include!(concat!(env!("OUT_DIR"), "/patterns.rs"));
+//! ```
+
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "regex-automata")]
+use regex_automata::dfa::dense::BuildError;
+
+#[cfg(feature = "regex-automata")]
+use PatternBundle;
+use Verex;
+
+/// The errors that can occur when baking patterns
+#[derive(Debug)]
+pub enum BakeError {
+    /// The name is not a valid Rust identifier
+    InvalidName(String),
+    /// A pattern with the name was already added
+    Duplicate(String),
+    /// The pattern with the name does not compile, with the explanation of `Verex::explain_error()`
+    Compile(String, String),
+    /// `OUT_DIR` is not set, i.e. the code does not run in a build script
+    NoOutDir,
+    /// The file could not be written
+    Io(io::Error)
+}
+
+impl fmt::Display for BakeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BakeError::InvalidName(ref name) => write!(f, "'{}' is not a valid identifier", name),
+            BakeError::Duplicate(ref name) => write!(f, "pattern '{}' was already added", name),
+            BakeError::Compile(ref name, ref explanation) => write!(f, "pattern '{}' does not compile: {}", name, explanation),
+            BakeError::NoOutDir => write!(f, "OUT_DIR is not set, patterns can only be baked in a build script"),
+            BakeError::Io(ref error) => write!(f, "could not write patterns: {}", error),
+        }
+    }
+}
+
+impl error::Error for BakeError {}
+
+impl From<io::Error> for BakeError {
+    fn from(error: io::Error) -> BakeError {
+        BakeError::Io(error)
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' => {},
+        _ => return false,
+    }
+    name != "_" && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// A collection of named, validated patterns that are written as Rust source
+#[derive(Debug, Clone, Default)]
+pub struct PatternBaker {
+    patterns: Vec<(String, Verex)>
+}
+
+impl PatternBaker {
+    /// Create an empty baker
+    pub fn new() -> PatternBaker {
+        PatternBaker::default()
+    }
+
+    /// Add a pattern under the name of the `static` it becomes, after checking that it compiles
+    pub fn add(&mut self, name: &str, verex: &Verex) -> Result<&mut PatternBaker, BakeError> {
+        if !is_identifier(name) {
+            return Err(BakeError::InvalidName(name.to_owned()));
+        }
+        if self.patterns.iter().any(|pattern| pattern.0 == name) {
+            return Err(BakeError::Duplicate(name.to_owned()));
+        }
+        if let Err(error) = verex.compile() {
+            return Err(BakeError::Compile(name.to_owned(), verex.explain_error(&error)));
+        }
+        self.patterns.push((name.to_owned(), verex.clone()));
+        Ok(self)
+    }
+
+    /// Return the Rust source with a `pub static NAME: &str` holding the regex string of each pattern
+    pub fn to_source(& self) -> String {
+        let mut source = String::from("// generated by verex::bake::PatternBaker\n");
+        for (name, verex) in &self.patterns {
+            source.push_str(format!("pub static {}: &str = {:?};\n", name, verex.source()).as_ref());
+        }
+        source
+    }
+
+    /// Write the Rust source of `to_source()` to a file
+    pub fn write<P: AsRef<Path>>(& self, path: P) -> Result<(), BakeError> {
+        fs::write(path, self.to_source())?;
+        Ok(())
+    }
+
+    /// Write the Rust source of `to_source()` to a file in `OUT_DIR` and return its path
+    ///
+    /// The file is only rewritten if its content changed, so the crate is not rebuilt needlessly.
+    pub fn write_to_out_dir(& self, file_name: &str) -> Result<PathBuf, BakeError> {
+        let path = Path::new(&env::var_os("OUT_DIR").ok_or(BakeError::NoOutDir)?).join(file_name);
+        let source = self.to_source();
+        if fs::read_to_string(&path).ok().as_ref() != Some(&source) {
+            fs::write(&path, source)?;
+        }
+        Ok(path)
+    }
+
+    /// Compile all patterns to DFAs in a `PatternBundle`, whose `to_bytes()` can be written to a file
+    /// and loaded with `include_bytes!` and `PatternBundle::from_bytes()`
+    #[cfg(feature = "regex-automata")]
+    #[allow(clippy::result_large_err)]
+    pub fn to_bundle(& self) -> Result<PatternBundle, BuildError> {
+        let mut bundle = PatternBundle::new();
+        for (name, verex) in &self.patterns {
+            bundle.add(name, verex)?;
+        }
+        Ok(bundle)
+    }
+}
@@ -0,0 +1,81 @@
+//! Representative patterns and texts for benchmarks
+//!
+//! All generators are deterministic, so the same workload can be measured with different regex
+//! backends (e.g. `compile()`, `compile_fancy()` and the DFAs) or different versions of this crate.
+//! The `benches/` suite of the crate is built on them.
+
+use patterns;
+use Verex;
+
+/// A word made of lowercase letters that is unique for every index
+pub fn word(index: usize) -> String {
+    let mut word = String::new();
+    let mut rest = index;
+    loop {
+        word.insert(0, (b'a' + (rest % 26) as u8) as char);
+        rest /= 26;
+        if rest == 0 {
+            return word;
+        }
+        rest -= 1;
+    }
+}
+
+/// A `Verex` built from n builder calls mixing literals, tokens and quantifiers
+pub fn builder_chain(n: usize) -> Verex {
+    let mut verex = Verex::new();
+    for i in 0..n {
+        match i % 4 {
+            0 => verex.find(word(i).as_ref()),
+            1 => verex.digit(),
+            2 => verex.maybe("-"),
+            _ => verex.word(),
+        };
+    }
+    verex
+}
+
+/// A `Verex` matching any of n distinct words
+pub fn word_alternation(n: usize) -> Verex {
+    let mut verex = Verex::new();
+    for i in 0..n {
+        if i == 0 {
+            verex.find(word(i).as_ref());
+        }
+        else {
+            verex.or_find(word(i).as_ref());
+        }
+    }
+    verex
+}
+
+/// n literals for escaping, every other one contains characters with a special meaning
+pub fn escape_inputs(n: usize) -> Vec<String> {
+    (0..n).map(|i| if i % 2 == 0 { word(i) } else { format!("{}.(*)?", word(i)) }).collect()
+}
+
+/// A selection of the presets of the `patterns` module together with their names
+pub fn presets() -> Vec<(&'static str, Verex)> {
+    vec![
+        ("email", patterns::email()),
+        ("url", patterns::url()),
+        ("float", patterns::float(Some(','))),
+        ("hostname", patterns::hostname(false)),
+        ("percentage", patterns::percentage()),
+        ("sha1", patterns::sha1()),
+    ]
+}
+
+/// A text of the given number of lines, each containing something every one of the `presets()` finds
+pub fn sample_text(lines: usize) -> String {
+    let mut text = String::new();
+    for i in 0..lines {
+        let name = word(i);
+        text.push_str(&format!(
+            "{name} wrote to {name}@example.com about https://{name}.example.org/page?id={i}, \
+             a rise of {i}.5% to 1,{i:03}.25 with commit {i:040x}\n",
+            name = name, i = i
+        ));
+    }
+    text
+}
@@ -2,6 +2,9 @@
 //! strings without knowing the minutiae of regex syntax.
 //!
 //! It uses the `Regex` crate to compile the created regex strings.
+//! Compiling and matching are behind the default `regex` feature. Without it the crate only builds
+//! and exports pattern strings, e.g. for other engines or build scripts, and only depends on the
+//! `regex-syntax` parser and `bitflags` instead of the whole regex engine.
 //!
 //! # Examples
 //!
@@ -13,6 +16,7 @@
 //! use verex::find;
 //!
 //! # fn main() {
+//! # #[cfg(feature = "regex")] {
 //!     // You can either use a mutable Verex to define different regexes
 //!     let mut verex = Verex::new();
 //!     let regex1 = verex.find("a")
@@ -39,6 +43,7 @@
 //!     assert_eq!(regex2.as_str(), r"(?:(?:a)|(?:b))");
 //!     assert_eq!(regex3.as_str(), r"(?:(?:a)|(?:b))");
 //! # }
+//! # }
 //! ```
 //!
 //!
@@ -49,6 +54,7 @@
 //! use verex::start_of_line;
 //!
 //! # fn main() {
+//! # #[cfg(feature = "regex")] {
 //!     // Create an example of how to test for correctly formed URLs
 //!     let verex = start_of_line()
 //!                 .find("http")
@@ -69,6 +75,7 @@
 //!     // Test the generated regex string
 //!     assert_eq!(verex.source(), r"(?:^(?:http)(?:s)?(?:://)(?:www\.)?(?:[^ ]*)$)");
 //! # }
+//! # }
 //! ```
 //!
 //! Example usage of the `or!` macro:
@@ -78,6 +85,7 @@
 //! extern crate verex;
 //!
 //! # fn main() {
+//! # #[cfg(feature = "regex")] {
 //!     let regex = or!("foo", "bar", "baz")
 //!                 .compile()
 //!                 .unwrap();
@@ -91,6 +99,7 @@
 //!     // Test the generated regex string
 //!     assert_eq!(regex.as_str(), r"(?:(?:foo)|(?:bar)|(?:baz))");
 //! # }
+//! # }
 //! ```
 //!
 //! Example usage of the `or_expr!` macro:
@@ -98,12 +107,15 @@
 //! ```rust
 //! #[macro_use(or_expr)]
 //! extern crate verex;
+//! # #[cfg(feature = "regex")]
 //! extern crate regex;
 //! use verex::Expression as E;
 //! use verex::Verex;
+//! # #[cfg(feature = "regex")]
 //! use regex::Regex;
 //!
 //! # fn main() {
+//! # #[cfg(feature = "regex")] {
 //!     let sub_verex = Verex::from_str("Darth(Vader)*?");
 //!     let sub_regex = Regex::new("(?P<robot>C3PO)").unwrap();
 //!     let regex = or_expr!(
@@ -122,88 +134,414 @@
 //!     // Test the generated regex string
 //!     assert_eq!(regex.as_str(), r"(?:(?:([RD]2){2})|(?:(?:Darth(Vader)*?))|(?:(?P<robot>C3PO)))");
 //! # }
+//! # }
 //! ```
 
 #![warn(missing_docs)]
 
 #[macro_use]
 extern crate bitflags;
+#[cfg(feature = "clap")]
+extern crate clap;
+#[cfg(feature = "regex")]
 extern crate regex;
+extern crate regex_syntax;
+#[cfg(feature = "fancy-regex")]
+extern crate fancy_regex;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "regex-automata")]
+extern crate regex_automata;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "tracing")]
+#[macro_use]
+extern crate tracing;
+#[cfg(all(test, feature = "grapheme"))]
+extern crate unicode_segmentation;
+#[cfg(feature = "wasm")]
+extern crate wasm_bindgen;
+
+use std::ops::RangeBounds;
 
 pub use verex::Verex;
+#[cfg(feature = "regex-automata")]
+pub use verex::AnalysisError;
+#[cfg(feature = "regex-automata")]
+pub use verex::BundleError;
+pub use verex::CaptureStep;
+pub use verex::CaseFolding;
+pub use verex::CharSet;
+pub use verex::CompileOptions;
+pub use verex::DslError;
+pub use verex::DslErrorKind;
+pub use verex::EscapeMode;
 pub use verex::Expression;
+pub use verex::LineBreak;
+pub use verex::ModifierSet;
+pub use verex::PartDiff;
+#[cfg(feature = "regex")]
+pub use verex::Pipeline;
+#[cfg(feature = "regex")]
+pub use verex::PipelineOutput;
+pub use verex::ExportError;
+#[cfg(feature = "regex")]
+pub use verex::FindAtMost;
+#[cfg(feature = "regex")]
+pub use verex::FindValid;
+pub use verex::JoinPolicy;
+pub use verex::JsImportError;
+#[cfg(feature = "regex-automata")]
+pub use verex::PatternBundle;
+#[cfg(feature = "regex")]
+pub use verex::PatternRegistry;
+pub use verex::RangeError;
+pub use verex::RangeOptions;
+#[cfg(feature = "regex")]
+pub use verex::RegistryError;
+#[cfg(feature = "regex")]
+pub use verex::RouteMatch;
+#[cfg(feature = "regex")]
+pub use verex::RoutePolicy;
+#[cfg(feature = "regex")]
+pub use verex::Segment;
+#[cfg(feature = "regex")]
+pub use verex::Segments;
+pub use verex::Shell;
+#[cfg(feature = "regex")]
+pub use verex::Token;
+#[cfg(feature = "regex")]
+pub use verex::TokenizeError;
+#[cfg(feature = "regex")]
+pub use verex::Tokenizer;
+#[cfg(feature = "regex")]
+pub use verex::Tokens;
+#[cfg(feature = "regex")]
+pub use verex::Validator;
+pub use verex::VerexConfig;
+pub use verex::VerexDiff;
+pub use verex::VerexFragment;
+#[cfg(feature = "regex")]
+pub use verex::VerexMatch;
+#[cfg(feature = "regex")]
+pub use verex::VerexProvider;
+#[cfg(feature = "regex")]
+pub use verex::VerexRouter;
+#[cfg(feature = "regex")]
+pub use verex::Violation;
+#[cfg(all(feature = "clap", feature = "regex"))]
+pub use verex::VerexValueParser;
+pub use verex::escape;
+#[cfg(feature = "regex")]
+pub use verex::{clear_shared_cache, shared_cache_len};
 
+// writes to `OUT_DIR` from build scripts, so it is left out of wasm builds
+#[cfg(all(feature = "regex", not(target_arch = "wasm32")))]
+pub mod bake;
+pub mod bench_support;
+pub mod patterns;
+#[cfg(all(feature = "serde", feature = "regex"))]
+pub mod serde_validate;
+#[cfg(feature = "rand")]
+pub mod testing;
 mod verex;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 // standalone functions
-/// Any of the given characters
-pub fn any(chars: &str) -> Verex {
-    Verex::new().any(chars).clone()
+/// Define a function for each builder method that starts a new `Verex` with it
+macro_rules! standalone_functions {
+    ( $( $(#[$attr:meta])* fn $name:ident $([$($generics:tt)*])* ($($arg:ident: $ty:ty),*) $([where $($bounds:tt)*])*; )* ) => {
+        $(
+            $(#[$attr])*
+            pub fn $name $(<$($generics)*>)* ($($arg: $ty),*) -> Verex $(where $($bounds)*)* {
+                Verex::new().$name($($arg),*).clone()
+            }
+        )*
+    };
 }
 
-/// See any()
-pub fn any_of(chars: &str) -> Verex {
-    any(chars)
-}
+standalone_functions! {
+    /// Wrap everything built so far in a group and add a sub-expression (won't be escaped) as alternative
+    fn alternate_with(expr: Expression);
 
-/// Any character zero or more times
-pub fn anything() -> Verex {
-    Verex::new().anything().clone()
-}
+    /// Any of the given characters
+    fn any(chars: &str);
 
-/// Any character zero or more times except the provided characters
-pub fn anything_but(chars: &str) -> Verex {
-    Verex::new().anything_but(chars).clone()
-}
+    /// Any of the characters matched by a sub-expression
+    #[track_caller]
+    fn any_expr(expr: Expression);
 
-/// A line break!
-pub fn br() -> Verex {
-    line_break()
-}
+    /// Any of the characters in the set
+    #[track_caller]
+    fn any_set(set: &CharSet);
 
-/// Find a specific string and capture it (will get escaped)
-pub fn capture(value: &str) -> Verex {
-    Verex::new().capture(value).clone()
-}
+    /// See any()
+    fn any_of(chars: &str);
 
-/// Find an expression and capture it
-pub fn capture_expr(expr: Expression) -> Verex {
-    Verex::new().capture_expr(expr).clone()
-}
+    /// Toggle whether `digit()` only matches the ASCII digits 0-9
+    fn ascii_digits(enable: bool);
 
-/// Add the token for matching digits
-pub fn digit() -> Verex {
-    Verex::new().digit().clone()
-}
+    /// Toggle whether the word methods only match ASCII letters, digits and `_`
+    fn ascii_words(enable: bool);
 
-/// Add a token for the end of a line
-pub fn end_of_line() -> Verex {
-    Verex::new().end_of_line().clone()
-}
+    /// Toggle whether groups added by `capture()` get generated names (`cap_1`, `cap_2`, ...)
+    fn auto_capture(enable: bool);
 
-/// Find a specific string
-pub fn find(value: &str) -> Verex {
-    Verex::new().find(value).clone()
-}
+    /// Any character zero or more times
+    fn anything();
 
-/// Find an expression
-pub fn find_expr(expr: Expression) -> Verex {
-    Verex::new().find_expr(expr).clone()
-}
+    /// Any character zero or more times except the provided characters
+    fn anything_but(chars: &str);
 
-/// A line break!
-pub fn line_break() -> Verex {
-    Verex::new().line_break().clone()
-}
+    /// Any character zero or more times except the characters matched by a sub-expression
+    #[track_caller]
+    fn anything_but_expr(expr: Expression);
 
-/// Any string either one or zero times
-pub fn maybe(value: &str) -> Verex {
-    Verex::new().maybe(value).clone()
-}
+    /// Add the expression of another `Verex` as a group, carrying over the origins of its capturing groups
+    fn append(other: &Verex);
+
+    /// Build a sub-expression in a closure and add it as an atomic group
+    #[cfg(feature = "fancy-regex")]
+    fn atomic_group[F](build: F) [where F: FnOnce(&mut Verex) -> &mut Verex];
+
+    /// Limit the number of backtracking steps a regex from `compile_fancy()` may take per match
+    #[cfg(feature = "fancy-regex")]
+    fn backtrack_limit(limit: usize);
+
+    /// Text enclosed by the open and close characters with nested pairs balanced up to a depth
+    fn balanced(open: char, close: char, max_depth: u32);
+
+    /// Text enclosed by the open and close characters with nested pairs balanced at any depth
+    #[cfg(feature = "fancy-regex")]
+    fn balanced_recursive(open: char, close: char);
+
+    /// A line break!
+    fn br();
+
+    /// Build a named alternative in a closure, consecutive branches are alternatives of each other
+    fn branch[F](name: &str, build: F) [where F: FnOnce(&mut Verex) -> &mut Verex];
+
+    /// Add a token for a single byte
+    fn byte(byte: u8);
+
+    /// Add a token for any byte in the range from `from` to `to` (both included)
+    fn byte_range(from: u8, to: u8);
+
+    /// Find a specific string and capture it (will get escaped)
+    #[track_caller]
+    fn capture(value: &str);
+
+    /// Find an expression and capture it
+    #[track_caller]
+    fn capture_expr(expr: Expression);
+
+    /// Add a sub-expression (won't be escaped) repeated as often as the range allows and capture all repetitions
+    #[track_caller]
+    fn capture_repeated[R: RangeBounds<u32>](name: &str, expr: Expression, bounds: R);
+
+    /// Toggle whether `anything()` and `something()` add capturing groups
+    fn capture_wildcards(enable: bool);
+
+    /// Add a token for a carriage return (`\r`)
+    fn carriage_return();
+
+    /// Add the token for matching a control character
+    fn control_char();
+
+    /// Add the token for matching digits
+    fn digit();
+
+    /// Add the token for matching decimal digits of any script
+    fn digit_unicode();
+
+    /// Add the token for matching exactly n digits
+    fn digits(n: u32);
+
+    /// Add the token for matching n to m digits
+    fn digits_between(n: u32, m: u32);
+
+    /// Add a token for the end of a line
+    fn end_of_line();
+
+    /// Find a specific string that has to make up a whole line
+    fn exactly_line(value: &str);
+
+    /// Add a predicate that matches found by `find_valid_iter()` have to pass
+    fn filter[F](predicate: F) [where F: Fn(&str) -> bool + Send + Sync + 'static];
+
+    /// Find a specific string
+    fn find(value: &str);
+
+    /// Find a specific string regardless of the case of its letters
+    fn find_any_case(value: &str);
+
+    /// Find an expression
+    fn find_expr(expr: Expression);
+
+    /// Find a value that is escaped according to the mode
+    fn find_with(value: &str, mode: EscapeMode);
+
+    /// Set how `with_any_case()` makes the expression case-insensitive
+    fn fold_case(folding: CaseFolding);
+
+    /// A full extended grapheme cluster
+    #[cfg(feature = "grapheme")]
+    fn grapheme();
+
+    /// Add a `\xNN` escape, which matches the character U+00NN
+    fn hex_escape(byte: u8);
+
+    /// Build a sub-expression in a closure that has to make up a whole line
+    fn line[F](build: F) [where F: FnOnce(&mut Verex) -> &mut Verex];
+
+    /// A line break!
+    fn line_break();
+
+    /// Toggle whether literal alternatives try longer alternatives first
+    fn longest_match(enable: bool);
+
+    /// Any string either one or zero times
+    fn maybe(value: &str);
+
+    /// Any string either one or zero times
+    fn maybe_expr(expr: Expression);
+
+    /// A digit either one or zero times
+    fn maybe_digit();
+
+    /// A whitespace character either one or zero times
+    fn maybe_whitespace();
+
+    /// Alphanumeric characters either one or zero times
+    fn maybe_word();
 
-/// Any string either one or zero times
-pub fn maybe_expr(expr: Expression) -> Verex {
-    Verex::new().maybe_expr(expr).clone()
+    /// Toggle whether ^ and $ match line start and end instead of string start and end
+    fn multi_line(enable: bool);
+
+    /// Add a token for a newline (`\n`), unlike `line_break()` without `\r`
+    fn newline();
+
+    /// Add the token for matching a character that is not printable
+    fn non_printable();
+
+    /// Either match the sub-expression before or after this
+    fn or();
+
+    /// Either match the sub-expression before or the provided value
+    fn or_find(value: &str);
+
+    /// Either match the sub-expression before or the provided sub-expression
+    fn or_find_expr(expr: Expression);
+
+    /// Either match the sub-expression before or a sub-expression built in a closure
+    fn or_group[F](build: F) [where F: FnOnce(&mut Verex) -> &mut Verex];
+
+    /// Add the token for matching a printable character
+    fn printable();
+
+    /// Add a fragment like a domain type implementing `VerexFragment`
+    fn push[F: VerexFragment + ?Sized](fragment: &F);
+
+    /// Add the token for matching a punctuation character of any script
+    fn punctuation();
+
+    /// Add the token for matching an ASCII punctuation character
+    fn punctuation_ascii();
+
+    /// A range of characters e.g. [A-Z]
+    /// Usage example: verex.range(vec![('a', 'z'),('A', 'Z')])
+    fn range(range: Vec<(char, char)>);
+
+    /// Add a sub-expression (won't be escaped) repeated as often as the range allows
+    fn repeat_expr[R: RangeBounds<u32>](expr: Expression, bounds: R);
+
+    /// Repeat the previous item n times
+    fn repeat_n(n: u32);
+
+    /// Repeat the previous item n to m times
+    fn repeat_n_to_m(n: u32, m: u32);
+
+    /// Repeat the previous item n to m times without ever giving back repetitions
+    #[cfg(feature = "fancy-regex")]
+    fn repeat_n_to_m_possessive(n: u32, m: u32);
+
+    /// Repeat the previous item once or more times
+    fn repeat_once_or_more();
+
+    /// Repeat the previous item once or more times without ever giving back repetitions
+    #[cfg(feature = "fancy-regex")]
+    fn repeat_once_or_more_possessive();
+
+    /// Repeat the previous item n times
+    fn repeat_previous(n: u32);
+
+    /// Repeat the previous item zero or more times
+    fn repeat_zero_or_more();
+
+    /// Repeat the previous item zero or more times without ever giving back repetitions
+    #[cfg(feature = "fancy-regex")]
+    fn repeat_zero_or_more_possessive();
+
+    /// Toggle whether ^ and $ match line start and end or string start and end
+    fn search_one_line(enable: bool);
+
+    /// Replace all modifiers
+    fn set_modifiers(modifiers: ModifierSet);
+
+    /// Toggle whether ^ and $ match string start and end instead of line start and end
+    fn single_line(enable: bool);
+
+    /// Any character at least one time
+    fn something();
+
+    /// Any character at least one time except for these characters
+    fn something_but(chars: &str);
+
+    /// Any character at least one time except the characters matched by a sub-expression
+    #[track_caller]
+    fn something_but_expr(expr: Expression);
+
+    /// Add a token for a single space
+    fn space();
+
+    /// Add a token for the start of a line
+    fn start_of_line();
+
+    /// Add the token for matching a symbol of any script
+    fn symbol();
+
+    /// Add the token for matching an ASCII symbol
+    fn symbol_ascii();
+
+    /// Add a token for a tab
+    fn tab();
+
+    /// To use find "in the sentence" and make the chaining flow better
+    fn then(value: &str);
+
+    /// Toggle whether optional whitespace is allowed between subsequently added tokens
+    fn tolerate_whitespace(enable: bool);
+
+    /// Toggle whether the source location of each subsequent builder call is recorded
+    fn track_locations(enable: bool);
+
+    /// Toggle whether to match case-sensitively or not
+    fn with_any_case(enable: bool);
+
+    /// Any alphanumeric characters bounded by word boundaries on both sides
+    fn whole_word();
+
+    /// Allow the given sub-expression between subsequently added tokens
+    fn with_separator(expr: Expression);
+
+    /// Any alphanumeric characters
+    fn word();
+
+    /// A single alphanumeric character
+    fn word_char();
 }
 
 /// Match any of the given values
@@ -235,44 +573,3 @@ macro_rules! or_expr {
         }
     };
 }
-
-/// A range of characters e.g. [A-Z]
-/// Usage example: verex.range(vec![('a', 'z'),('A', 'Z')])
-pub fn range(range: Vec<(char, char)>) -> Verex {
-    Verex::new().range(range).clone()
-}
-
-/// Toggle whether ^ and $ match line start and end or string start and end
-pub fn search_one_line(enable: bool) -> Verex {
-    Verex::new().search_one_line(enable).clone()
-}
-
-/// Any character at least one time
-pub fn something() -> Verex {
-    Verex::new().something().clone()
-}
-
-/// Any character at least one time except for these characters
-pub fn something_but(chars: &str) -> Verex {
-    Verex::new().something_but(chars).clone()
-}
-
-/// Add a token for the start of a line
-pub fn start_of_line() -> Verex {
-    Verex::new().start_of_line().clone()
-}
-
-/// Add a token for a tab
-pub fn tab() -> Verex {
-    Verex::new().tab().clone()
-}
-
-/// Toggle whether to match case-sensitively or not
-pub fn with_any_case(enable: bool) -> Verex {
-    Verex::new().with_any_case(enable).clone()
-}
-
-/// Any alphanumeric characters
-pub fn word() -> Verex {
-    Verex::new().word().clone()
-}
@@ -0,0 +1,59 @@
+use Expression as E;
+use Verex;
+
+/// Whether the digits of the text pass the Luhn check used by credit card numbers, ignoring
+/// spaces and hyphens
+pub fn luhn(text: &str) -> bool {
+    let mut sum = 0;
+    let mut count = 0;
+    for c in text.chars().rev().filter(|&c| c != ' ' && c != '-') {
+        let digit = match c.to_digit(10) {
+            Some(digit) => digit,
+            None => return false,
+        };
+        sum += if count % 2 == 1 {
+            if digit * 2 > 9 { digit * 2 - 9 } else { digit * 2 }
+        }
+        else {
+            digit
+        };
+        count += 1;
+    }
+    count > 0 && sum % 10 == 0
+}
+
+/// Whether the text passes the ISO 13616 mod-97 check of IBANs, ignoring spaces
+pub fn iban_mod97(text: &str) -> bool {
+    let iban: Vec<char> = text.chars().filter(|&c| c != ' ').collect();
+    if iban.len() < 5 {
+        return false;
+    }
+    // move the country code and check digits to the end and turn letters into numbers
+    let mut remainder = 0;
+    for &c in iban[4..].iter().chain(iban[..4].iter()) {
+        let value = match c.to_digit(36) {
+            Some(value) => value,
+            None => return false,
+        };
+        let shift = if value < 10 { 10 } else { 100 };
+        remainder = (remainder * shift + value) % 97;
+    }
+    remainder == 1
+}
+
+/// Find a credit card number of 13 to 19 digits, optionally grouped by spaces or hyphens,
+/// with a `luhn()` filter for `find_valid_iter()`
+pub fn credit_card() -> Verex {
+    Verex::new()
+        .find_expr(E::String(r"\b[0-9](?:[ \x2D]?[0-9]){12,18}\b"))
+        .filter(luhn)
+        .clone()
+}
+
+/// Find an IBAN like `DE89 3704 0044 0532 0130 00` with an `iban_mod97()` filter for `find_valid_iter()`
+pub fn iban() -> Verex {
+    Verex::new()
+        .find_expr(E::String(r"\b[A-Z]{2}[0-9]{2}(?: ?[A-Z0-9]){11,30}\b"))
+        .filter(iban_mod97)
+        .clone()
+}
@@ -0,0 +1,70 @@
+use verex::escape;
+use Expression as E;
+use Verex;
+
+use super::named;
+
+/// How values of `key_value()` lines are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyValueOptions {
+    /// Whether values may be enclosed in `"` (with backslash escapes) or `'`
+    pub quoted_values: bool,
+    /// Whether a `#` or `;` starts a trailing comment after the value
+    pub trailing_comments: bool
+}
+
+impl Default for KeyValueOptions {
+    /// Quoted values and trailing comments as in most dotenv and INI files
+    fn default() -> KeyValueOptions {
+        KeyValueOptions { quoted_values: true, trailing_comments: true }
+    }
+}
+
+/// The end of a line with an optional trailing comment
+fn line_end(trailing_comments: bool) -> String {
+    if trailing_comments {
+        format!(r"\s*(?:[#;]{})?\r?", named("comment", r"[^\r\n]*"))
+    }
+    else {
+        r"\s*\r?".to_owned()
+    }
+}
+
+/// Match a whole line assigning a value to a key like `KEY=value`, `export KEY="value"` or
+/// `key: value # comment`
+///
+/// Captures `key`, `value` (including the quotes of a quoted value) and `comment`. Use
+/// `multi_line(true)` to find the lines of a whole file.
+pub fn key_value(separator: char, options: KeyValueOptions) -> Verex {
+    let unquoted = if options.trailing_comments { r"[^#;\r\n]*?" } else { r"[^\r\n]*?" };
+    let value = if options.quoted_values {
+        format!(r#""(?:[^"\\\r\n]|\\.)*"|'[^'\r\n]*'|{}"#, unquoted)
+    }
+    else {
+        unquoted.to_owned()
+    };
+    let assignment = format!(r"\s*{}\s*", escape(separator.to_string().as_ref()));
+    Verex::new()
+        .start_of_line()
+        .find_expr(E::String(r"\s*(?:export\s+)?"))
+        .find_expr(E::String(named("key", r"[A-Za-z_][\w.\x2D]*").as_ref()))
+        .find_expr(E::String(assignment.as_ref()))
+        .find_expr(E::String(named("value", value.as_ref()).as_ref()))
+        .find_expr(E::String(line_end(options.trailing_comments).as_ref()))
+        .end_of_line()
+        .clone()
+}
+
+/// Match a whole line with an INI section header like `[database]`, with an optional trailing comment
+///
+/// Captures `section` and `comment`.
+pub fn ini_section() -> Verex {
+    Verex::new()
+        .start_of_line()
+        .find_expr(E::String(r"\s*\[\s*"))
+        .find_expr(E::String(named("section", r"[^\]\r\n]+?").as_ref()))
+        .find_expr(E::String(r"\s*\]"))
+        .find_expr(E::String(line_end(true).as_ref()))
+        .end_of_line()
+        .clone()
+}
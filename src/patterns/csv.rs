@@ -0,0 +1,47 @@
+use verex::{escape, escape_class_char};
+use Expression as E;
+use Verex;
+
+use super::named;
+
+/// A quoted field with doubled quotes inside or an unquoted field without delimiters, quotes and line breaks
+fn field(delimiter: char, quote: char, capture: bool) -> String {
+    let escaped_quote = escape(quote.to_string().as_ref()).into_owned();
+    let quoted = format!("(?:[^{}]|{}{})*", escape_class_char(quote), escaped_quote, escaped_quote);
+    let unquoted = format!(r"[^{}{}\r\n]*", escape_class_char(delimiter), escape_class_char(quote));
+    if capture {
+        format!("{quote}{quoted}{quote}|{unquoted}",
+                quote = escaped_quote,
+                quoted = named("quoted", quoted.as_ref()),
+                unquoted = named("unquoted", unquoted.as_ref()))
+    }
+    else {
+        format!("{quote}(?:{quoted}){quote}|{unquoted}", quote = escaped_quote, quoted = quoted, unquoted = unquoted)
+    }
+}
+
+/// Find a single CSV/TSV field, which is either enclosed in quotes with quotes inside doubled
+/// (`"a, ""b"""`) or contains no delimiters, quotes or line breaks
+///
+/// Captures the content of a quoted field (with the quotes still doubled) as `quoted` and
+/// an unquoted field as `unquoted`.
+pub fn csv_field(delimiter: char, quote: char) -> Verex {
+    Verex::new()
+        .find_expr(E::String(field(delimiter, quote, true).as_ref()))
+        .clone()
+}
+
+/// Match a whole line of CSV/TSV fields separated by the delimiter
+///
+/// Use `multi_line(true)` to find rows in a multi-line text and `csv_field()` to extract the fields.
+pub fn csv_row(delimiter: char, quote: char) -> Verex {
+    let field = field(delimiter, quote, false);
+    let more_fields = format!("{}(?:{})", escape(delimiter.to_string().as_ref()), field);
+    Verex::new()
+        .start_of_line()
+        .find_expr(E::String(field.as_ref()))
+        .find_expr(E::String(more_fields.as_ref()))
+        .repeat_zero_or_more()
+        .end_of_line()
+        .clone()
+}
@@ -0,0 +1,54 @@
+#[cfg(feature = "regex")]
+use std::sync::OnceLock;
+#[cfg(feature = "regex")]
+use std::time::Duration;
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+
+use Expression as E;
+use Verex;
+
+use super::named;
+
+/// Find a duration in compact form like `1h30m`, `90s` or `2d`, or in ISO 8601 form like `PT1H30M`
+///
+/// Captures `days`, `hours`, `minutes` and `seconds`, which may have decimals. As the units are
+/// all optional and capture names must be unique, the preset also matches an empty string: add
+/// `start_of_line()` and `end_of_line()` to validate, or use `extract_durations()` to search text.
+pub fn duration() -> Verex {
+    let number = r"[0-9]+(?:\.[0-9]+)?";
+    Verex::new()
+        .find_expr(E::String(r"\bP?"))
+        .maybe_expr(E::String(format!("{}[dD]", named("days", number)).as_ref()))
+        .find_expr(E::String(r"T?"))
+        .maybe_expr(E::String(format!("{}[hH]", named("hours", number)).as_ref()))
+        .maybe_expr(E::String(format!("{}[mM]", named("minutes", number)).as_ref()))
+        .maybe_expr(E::String(format!("{}[sS]", named("seconds", number)).as_ref()))
+        .find_expr(E::String(r"\b"))
+        .clone()
+}
+
+/// Extract all durations from the text, skipping durations too long for a `Duration`
+#[cfg(feature = "regex")]
+pub fn extract_durations(text: &str) -> Vec<Duration> {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = REGEX.get_or_init(|| duration().compile().unwrap());
+    regex.captures_iter(text).filter_map(|captures| {
+        let units = [("days", 86400.0), ("hours", 3600.0), ("minutes", 60.0), ("seconds", 1.0)];
+        let mut seconds = 0.0;
+        let mut found = false;
+        for &(name, factor) in units.iter() {
+            if let Some(value) = captures.name(name) {
+                seconds += value.parse::<f64>().ok()? * factor;
+                found = true;
+            }
+        }
+        if found {
+            Duration::try_from_secs_f64(seconds).ok()
+        }
+        else {
+            None
+        }
+    }).collect()
+}
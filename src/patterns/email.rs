@@ -0,0 +1,45 @@
+#[cfg(feature = "regex")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use Expression as E;
+use Verex;
+
+use super::named;
+
+/// The components of an email address found by `extract_email()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct EmailParts {
+    /// The part before the `@`
+    pub local: String,
+    /// The domain after the `@`
+    pub domain: String
+}
+
+/// Find an email address like `jane.doe+news@example.com`
+///
+/// Captures `local` and `domain`. Quoted local parts and address literals are not supported.
+pub fn email() -> Verex {
+    Verex::new()
+        .find_expr(E::String(named("local", r"[A-Za-z0-9.!#$%&'*+/=?^_`{|}~\x2D]+").as_ref()))
+        .find("@")
+        .find_expr(E::String(named("domain", r"[A-Za-z0-9\x2D]+(?:\.[A-Za-z0-9\x2D]+)+").as_ref()))
+        .clone()
+}
+
+/// Extract the components of the first email address in the text
+#[cfg(feature = "regex")]
+pub fn extract_email(text: &str) -> Option<EmailParts> {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = REGEX.get_or_init(|| email().compile().unwrap());
+    let captures = regex.captures(text)?;
+    Some(EmailParts {
+        local: captures.name("local").unwrap_or("").to_owned(),
+        domain: captures.name("domain").unwrap_or("").to_owned()
+    })
+}
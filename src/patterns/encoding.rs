@@ -0,0 +1,93 @@
+use Expression as E;
+use Verex;
+
+/// The alphabet and padding of base64 encoded data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base64Config {
+    /// Whether the URL-safe alphabet with `-` and `_` is used instead of `+` and `/`
+    pub url_safe: bool,
+    /// Whether the data is padded with `=` to a multiple of 4 characters
+    pub padding: bool
+}
+
+impl Default for Base64Config {
+    /// The standard alphabet with padding
+    fn default() -> Base64Config {
+        Base64Config { url_safe: false, padding: true }
+    }
+}
+
+/// The base64 characters of the alphabet, in groups of 4 with the (padded) rest
+fn base64_pattern(min_quads: usize, config: Base64Config) -> String {
+    let alphabet = if config.url_safe { r"[A-Za-z0-9_\x2D]" } else { r"[A-Za-z0-9+/]" };
+    let rest = if config.padding {
+        format!("{a}{{2}}==|{a}{{3}}=", a = alphabet)
+    }
+    else {
+        format!("{}{{2,3}}", alphabet)
+    };
+    format!("(?:{a}{{4}}){{{min},}}(?:{rest})?", a = alphabet, min = min_quads, rest = rest)
+}
+
+/// Find a hexadecimal digest of exactly `length` digits, e.g. 32 for MD5
+pub fn hex_digest(length: usize) -> Verex {
+    Verex::new()
+        .find_expr(E::String(format!(r"\b[0-9a-fA-F]{{{}}}\b", length).as_ref()))
+        .clone()
+}
+
+/// Find a SHA-1 digest like a full git commit hash (40 hexadecimal digits)
+pub fn sha1() -> Verex {
+    hex_digest(40)
+}
+
+/// Find a SHA-256 digest (64 hexadecimal digits)
+pub fn sha256() -> Verex {
+    hex_digest(64)
+}
+
+/// Find an abbreviated git commit hash of 7 to 40 hexadecimal digits
+pub fn short_hash() -> Verex {
+    Verex::new()
+        .find_expr(E::String(r"\b[0-9a-fA-F]{7,40}\b"))
+        .clone()
+}
+
+/// Find base64 encoded data of at least `min_length` characters (rounded up to a multiple of 4)
+///
+/// The match is not delimited, so it should be combined with the surrounding syntax where possible.
+pub fn base64_blob(min_length: usize, config: Base64Config) -> Verex {
+    Verex::new()
+        .find_expr(E::String(base64_pattern(min_length.div_ceil(4), config).as_ref()))
+        .clone()
+}
+
+/// Match a whole string of base64 encoded data, e.g. to validate input
+pub fn base64(config: Base64Config) -> Verex {
+    Verex::new()
+        .start_of_line()
+        .find_expr(E::String(base64_pattern(0, config).as_ref()))
+        .end_of_line()
+        .clone()
+}
+
+/// Match a whole non-empty string of hexadecimal digits, with an even number of them if `even_length`
+/// as for encoded bytes
+pub fn hex_string(even_length: bool) -> Verex {
+    let digits = if even_length { r"(?:[0-9a-fA-F]{2})+" } else { r"[0-9a-fA-F]+" };
+    Verex::new()
+        .start_of_line()
+        .find_expr(E::String(digits))
+        .end_of_line()
+        .clone()
+}
+
+/// Match a whole non-empty string of the bits `0` and `1`
+pub fn bit_string() -> Verex {
+    Verex::new()
+        .start_of_line()
+        .any("01")
+        .repeat_once_or_more()
+        .end_of_line()
+        .clone()
+}
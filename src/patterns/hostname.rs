@@ -0,0 +1,49 @@
+use Expression as E;
+use Verex;
+
+use super::named;
+
+/// A label of 1 to 63 letters, digits and hyphens, not starting or ending with a hyphen and,
+/// as reserved by RFC 5891, without hyphens at both the third and fourth position
+const LABEL: &str = r"[A-Za-z0-9](?:[A-Za-z0-9\x2D]?[A-Za-z0-9]|[A-Za-z0-9\x2D]{2}[A-Za-z0-9]|[A-Za-z0-9\x2D](?:[A-Za-z0-9][A-Za-z0-9\x2D]|-[A-Za-z0-9])[A-Za-z0-9\x2D]{0,58}[A-Za-z0-9])?";
+
+/// A punycode encoded label of an internationalized domain name like `xn--bcher-kva`
+const PUNYCODE_LABEL: &str = r"[xX][nN]--[A-Za-z0-9\x2D]{0,58}[A-Za-z0-9]";
+
+fn label(punycode: bool) -> String {
+    if punycode {
+        format!("{}|{}", PUNYCODE_LABEL, LABEL)
+    }
+    else {
+        LABEL.to_owned()
+    }
+}
+
+/// Find a host name as in RFC 1123 like `localhost` or `db-1.internal`, accepting the `xn--` labels
+/// of internationalized names if `punycode`
+///
+/// The length of each label is limited to 63 characters, the total length is not checked.
+pub fn hostname(punycode: bool) -> Verex {
+    let label = label(punycode);
+    Verex::new()
+        .find_expr(E::String(format!(r"\b(?:{label})(?:\.(?:{label}))*\b", label = label).as_ref()))
+        .clone()
+}
+
+/// Find a domain name with at least two labels and an alphabetic top-level domain like
+/// `www.example.com`, accepting the `xn--` labels of internationalized names if `punycode`
+///
+/// Captures `tld`.
+pub fn domain(punycode: bool) -> Verex {
+    let label = label(punycode);
+    let tld = if punycode {
+        format!("{}|[A-Za-z]{{2,63}}", PUNYCODE_LABEL)
+    }
+    else {
+        "[A-Za-z]{2,63}".to_owned()
+    };
+    Verex::new()
+        .find_expr(E::String(format!(r"\b(?:(?:{})\.)+", label).as_ref()))
+        .find_expr(E::String(format!(r"{}\b", named("tld", tld.as_ref())).as_ref()))
+        .clone()
+}
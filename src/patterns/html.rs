@@ -0,0 +1,60 @@
+use verex::expand_any_case;
+use Expression as E;
+use Verex;
+
+use super::named;
+
+/// Any tag or attribute name
+const NAME: &str = r"[A-Za-z][\w:.\x2D]*";
+
+/// An attribute value in double or single quotes or without quotes
+const VALUE: &str = r#""[^"]*"|'[^']*'|[^\s"'=<>`]+"#;
+
+/// Find an opening, closing or self-closing HTML/XML tag with the given name (regardless of case),
+/// or any tag if the name is empty
+///
+/// Captures `closing` (the `/` of a closing tag), `name`, `attributes` (the raw attribute text
+/// including leading whitespace) and `self_closing` (the `/` of a self-closing tag).
+/// Quoted attribute values may contain `>`, but comments, CDATA sections and scripts are not
+/// taken into account.
+pub fn html_tag(name: &str) -> Verex {
+    let name = if name.is_empty() { NAME.to_owned() } else { expand_any_case(name) };
+    let attributes = format!(r#"(?:[^>"']|{})*?"#, VALUE);
+    Verex::new()
+        .find("<")
+        .maybe_expr(E::String(named("closing", "/").as_ref()))
+        .find_expr(E::String(named("name", name.as_ref()).as_ref()))
+        .find_expr(E::String(r"\b"))
+        .find_expr(E::String(named("attributes", attributes.as_ref()).as_ref()))
+        .maybe_expr(E::String(named("self_closing", "/").as_ref()))
+        .find(">")
+        .clone()
+}
+
+/// Find an attribute with the given name (regardless of case), or any attribute if the name is empty
+///
+/// Captures `name` and `value`, which includes the quotes of quoted values.
+pub fn html_attribute(name: &str) -> Verex {
+    let name = if name.is_empty() { NAME.to_owned() } else { expand_any_case(name) };
+    Verex::new()
+        .find_expr(E::String(r"(?:^|\s)"))
+        .find_expr(E::String(named("name", name.as_ref()).as_ref()))
+        .find_expr(E::String(r"\s*=\s*"))
+        .find_expr(E::String(named("value", VALUE).as_ref()))
+        .clone()
+}
+
+/// Find a named (`&amp;`), decimal (`&#38;`) or hexadecimal (`&#x26;`) character reference
+///
+/// Captures the reference as `named`, `decimal` or `hex`.
+pub fn html_entity() -> Verex {
+    let reference = format!("{}|#{}|#[xX]{}",
+                            named("named", r"[A-Za-z][A-Za-z0-9]*"),
+                            named("decimal", r"[0-9]+"),
+                            named("hex", r"[0-9A-Fa-f]+"));
+    Verex::new()
+        .find("&")
+        .find_expr(E::String(reference.as_ref()))
+        .find(";")
+        .clone()
+}
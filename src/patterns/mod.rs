@@ -0,0 +1,50 @@
+//! Pre-built verbal expressions for common formats
+//!
+//! The presets are lexical: they find text that looks like the format, but they do not parse
+//! or fully validate it. Each preset returns a `Verex` that can be extended like any other,
+//! and the interesting parts are available as named capturing groups.
+
+mod checksum;
+mod config;
+mod csv;
+mod duration;
+mod email;
+mod encoding;
+mod hostname;
+mod html;
+mod money;
+mod naming;
+mod numbers;
+mod path;
+mod postal;
+#[cfg(feature = "secrets")]
+pub mod secrets;
+mod url;
+
+pub use self::checksum::{credit_card, iban, iban_mod97, luhn};
+pub use self::config::{ini_section, key_value, KeyValueOptions};
+pub use self::csv::{csv_field, csv_row};
+pub use self::duration::duration;
+#[cfg(feature = "regex")]
+pub use self::duration::extract_durations;
+pub use self::email::{email, EmailParts};
+#[cfg(feature = "regex")]
+pub use self::email::extract_email;
+pub use self::encoding::{base64, base64_blob, bit_string, hex_digest, hex_string, sha1, sha256, short_hash, Base64Config};
+pub use self::hostname::{domain, hostname};
+pub use self::html::{html_attribute, html_entity, html_tag};
+pub use self::money::{currency, percentage};
+pub use self::naming::{camel_case, identifier, kebab_case, slug, snake_case};
+pub use self::numbers::{float, integer};
+#[cfg(feature = "regex")]
+pub use self::numbers::{extract_floats, extract_integers};
+pub use self::path::{posix_path, windows_path};
+pub use self::postal::{postal_code, CountryCode};
+pub use self::url::{url, UrlParts};
+#[cfg(feature = "regex")]
+pub use self::url::extract_url;
+
+/// Wrap a regex string in a named capturing group
+fn named(name: &str, pattern: &str) -> String {
+    format!("(?P<{}>{})", name, pattern)
+}
@@ -0,0 +1,47 @@
+use std::cmp::Reverse;
+
+use verex::escape;
+use Expression as E;
+use Verex;
+
+use super::named;
+
+/// An amount with an optional sign, thousands separators and decimals, e.g. `-1,234.56` or `1.234,56`
+const AMOUNT: &str = r"\x2D?[0-9]{1,3}(?:[,.'][0-9]{3})*(?:[.,][0-9]{1,2})?|\x2D?[0-9]+(?:[.,][0-9]{1,2})?";
+
+/// An alternation of the symbols, longest first so `US$` is preferred over `$`
+fn symbols_pattern(symbols: &[&str]) -> String {
+    if symbols.is_empty() {
+        return r"\p{Sc}|\b[A-Z]{3}\b".to_owned();
+    }
+    let mut symbols: Vec<&str> = symbols.to_vec();
+    symbols.sort_by_key(|symbol| Reverse(symbol.len()));
+    symbols.iter().map(|symbol| escape(symbol)).collect::<Vec<_>>().join("|")
+}
+
+/// Find an amount of money with one of the currency symbols or codes in front of or after it,
+/// e.g. `$5`, `€ 1.234,50` or `20 EUR`, or with any currency symbol or three letter code if
+/// `symbols` is empty
+///
+/// Captures `unit` and `amount` if the symbol comes first and `suffix_amount` and `suffix_unit`
+/// if it comes last, as capture names must be unique.
+pub fn currency(symbols: &[&str]) -> Verex {
+    let symbols = symbols_pattern(symbols);
+    let prefixed = format!(r"{}\s?{}", named("unit", symbols.as_ref()), named("amount", AMOUNT));
+    let suffixed = format!(r"{}\s?{}", named("suffix_amount", AMOUNT), named("suffix_unit", symbols.as_ref()));
+    Verex::new()
+        .find_expr(E::String(prefixed.as_ref()))
+        .or_find_expr(E::String(suffixed.as_ref()))
+        .clone()
+}
+
+/// Find a percentage (or per mille) like `12.5%`, `-3 %` or `7 percent`
+///
+/// Captures `amount` and `unit`.
+pub fn percentage() -> Verex {
+    Verex::new()
+        .find_expr(E::String(named("amount", r"[+\x2D]?[0-9]+(?:[.,][0-9]+)?").as_ref()))
+        .find_expr(E::String(r"\s?"))
+        .find_expr(E::String(named("unit", r"%|‰|‱|\bpercent\b").as_ref()))
+        .clone()
+}
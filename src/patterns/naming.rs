@@ -0,0 +1,70 @@
+use Expression as E;
+use Verex;
+
+fn lower_alnum() -> Vec<(char, char)> {
+    vec![('a', 'z'), ('0', '9')]
+}
+
+/// Words of lowercase letters and digits joined by the separator
+fn joined(separator: &str, letter_first: bool) -> Verex {
+    let mut verex = Verex::new();
+    if letter_first {
+        verex.range(vec![('a', 'z')])
+             .range(lower_alnum())
+             .repeat_zero_or_more();
+    }
+    else {
+        verex.range(lower_alnum())
+             .repeat_once_or_more();
+    }
+    let next = Verex::new()
+        .find(separator)
+        .range(lower_alnum())
+        .repeat_once_or_more()
+        .clone();
+    verex.find_expr(E::Verex(&next))
+         .repeat_zero_or_more();
+    verex
+}
+
+/// Find a URL slug like `hello-world-2`: lowercase letters and digits joined by single hyphens
+///
+/// Add `start_of_line()` and `end_of_line()` to validate a whole string.
+pub fn slug() -> Verex {
+    joined("-", false)
+}
+
+/// Find an identifier as in most programming languages, i.e. `[A-Za-z_][A-Za-z0-9_]*`
+pub fn identifier() -> Verex {
+    Verex::new()
+        .range(vec![('A', 'Z'), ('a', 'z'), ('_', '_')])
+        .range(vec![('A', 'Z'), ('a', 'z'), ('0', '9'), ('_', '_')])
+        .repeat_zero_or_more()
+        .clone()
+}
+
+/// Find a kebab-case name like `max-line-length`
+pub fn kebab_case() -> Verex {
+    joined("-", true)
+}
+
+/// Find a snake_case name like `max_line_length`
+pub fn snake_case() -> Verex {
+    joined("_", true)
+}
+
+/// Find a camelCase name like `maxLineLength`, starting with a lowercase letter
+pub fn camel_case() -> Verex {
+    let word = Verex::new()
+        .range(vec![('A', 'Z')])
+        .range(lower_alnum())
+        .repeat_zero_or_more()
+        .clone();
+    Verex::new()
+        .range(vec![('a', 'z')])
+        .range(lower_alnum())
+        .repeat_zero_or_more()
+        .find_expr(E::Verex(&word))
+        .repeat_zero_or_more()
+        .clone()
+}
@@ -0,0 +1,99 @@
+#[cfg(feature = "regex")]
+use std::collections::HashMap;
+#[cfg(feature = "regex")]
+use std::sync::{Mutex, OnceLock};
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+
+use verex::escape;
+use Expression as E;
+use Verex;
+
+use super::named;
+
+/// The digits of the integer part, optionally grouped in thousands
+fn integer_digits(thousands_separator: Option<char>) -> String {
+    match thousands_separator {
+        Some(separator) => format!("[0-9]{{1,3}}(?:{}[0-9]{{3}})+|[0-9]+", escape(separator.to_string().as_ref())),
+        None => "[0-9]+".to_owned(),
+    }
+}
+
+/// Find an integer with an optional sign, e.g. `-1,234` with `,` as thousands separator
+///
+/// Captures `sign` and `integer`.
+pub fn integer(thousands_separator: Option<char>) -> Verex {
+    Verex::new()
+        .maybe_expr(E::String(named("sign", r"[+\x2D]").as_ref()))
+        .find_expr(E::String(r"\b"))
+        .find_expr(E::String(named("integer", integer_digits(thousands_separator).as_ref()).as_ref()))
+        .find_expr(E::String(r"\b"))
+        .clone()
+}
+
+/// Find a decimal number with an optional sign, fraction and exponent, e.g. `-1,234.5e-3` with `,`
+/// as thousands separator
+///
+/// Captures `sign`, `integer`, `fraction` and `exponent`.
+pub fn float(thousands_separator: Option<char>) -> Verex {
+    Verex::new()
+        .maybe_expr(E::String(named("sign", r"[+\x2D]").as_ref()))
+        .find_expr(E::String(r"\b"))
+        .find_expr(E::String(named("integer", integer_digits(thousands_separator).as_ref()).as_ref()))
+        .maybe_expr(E::String(format!(r"\.{}", named("fraction", "[0-9]+")).as_ref()))
+        .maybe_expr(E::String(format!("[eE]{}", named("exponent", r"[+\x2D]?[0-9]+")).as_ref()))
+        .find_expr(E::String(r"\b"))
+        .clone()
+}
+
+/// The compiled `float()` preset for the thousands separator, each separator is only compiled once
+#[cfg(feature = "regex")]
+fn float_regex(thousands_separator: Option<char>) -> Regex {
+    static REGEXES: OnceLock<Mutex<HashMap<Option<char>, Regex>>> = OnceLock::new();
+    let mut regexes = REGEXES.get_or_init(|| Mutex::new(HashMap::new())).lock().unwrap_or_else(|error| error.into_inner());
+    regexes.entry(thousands_separator)
+           .or_insert_with(|| float(thousands_separator).compile().unwrap())
+           .clone()
+}
+
+/// The numbers in the text as string without thousands separators, and whether they are integers
+#[cfg(feature = "regex")]
+fn numbers(text: &str, thousands_separator: Option<char>) -> Vec<(String, bool)> {
+    let regex = float_regex(thousands_separator);
+    regex.captures_iter(text).map(|captures| {
+        let mut number = captures.name("sign").unwrap_or("").to_owned();
+        let integer = captures.name("integer").unwrap_or("");
+        match thousands_separator {
+            Some(separator) => number.extend(integer.chars().filter(|&c| c != separator)),
+            None => number.push_str(integer),
+        }
+        let is_integer = captures.name("fraction").is_none() && captures.name("exponent").is_none();
+        if let Some(fraction) = captures.name("fraction") {
+            number.push('.');
+            number.push_str(fraction);
+        }
+        if let Some(exponent) = captures.name("exponent") {
+            number.push('e');
+            number.push_str(exponent);
+        }
+        (number, is_integer)
+    }).collect()
+}
+
+/// Extract all integers from the text, skipping decimal numbers and integers that do not fit into an `i64`
+#[cfg(feature = "regex")]
+pub fn extract_integers(text: &str, thousands_separator: Option<char>) -> Vec<i64> {
+    numbers(text, thousands_separator).into_iter()
+        .filter(|&(_, is_integer)| is_integer)
+        .filter_map(|(number, _)| number.parse().ok())
+        .collect()
+}
+
+/// Extract all numbers from the text, including integers and numbers in scientific notation
+#[cfg(feature = "regex")]
+pub fn extract_floats(text: &str, thousands_separator: Option<char>) -> Vec<f64> {
+    numbers(text, thousands_separator).into_iter()
+        .filter_map(|(number, _)| number.parse().ok())
+        .collect()
+}
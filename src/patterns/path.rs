@@ -0,0 +1,44 @@
+use Expression as E;
+use Verex;
+
+/// A POSIX path component: anything but `/`, NUL and whitespace
+const POSIX_COMPONENT: &str = r"[^\x00/\s]+";
+
+/// A Windows path component: anything but the reserved and control characters and whitespace
+const WINDOWS_COMPONENT: &str = r#"[^<>:"/\\|?*\x00-\x1F\s]+"#;
+
+/// Find a POSIX path like `/usr/local/bin` or, unless `absolute`, `src/main.rs`
+///
+/// Relative paths need at least one `/`, otherwise every word would be a path. Components with
+/// whitespace are not supported, as the end of the path could not be told apart from the text after it.
+pub fn posix_path(absolute: bool) -> Verex {
+    let absolute_path = format!("/(?:{c}/)*(?:{c})?", c = POSIX_COMPONENT);
+    let pattern = if absolute {
+        absolute_path
+    }
+    else {
+        format!("{}|(?:{c}/)+(?:{c})?", absolute_path, c = POSIX_COMPONENT)
+    };
+    Verex::new()
+        .find_expr(E::String(pattern.as_ref()))
+        .clone()
+}
+
+/// Find a Windows path starting with a drive letter like `C:\Windows` or a UNC share like
+/// `\\server\share\file.txt` or, unless `absolute`, a relative path like `docs\readme.md`
+///
+/// Both `\` and `/` are accepted as separators. Like `posix_path()`, relative paths need at least
+/// one separator and components with whitespace are not supported.
+pub fn windows_path(absolute: bool) -> Verex {
+    let root = format!(r"[A-Za-z]:[\\/]|\\\\{c}[\\/]{c}[\\/]?", c = WINDOWS_COMPONENT);
+    let absolute_path = format!(r"(?:{root})(?:{c}[\\/])*(?:{c})?", root = root, c = WINDOWS_COMPONENT);
+    let pattern = if absolute {
+        absolute_path
+    }
+    else {
+        format!(r"{}|[\\/]?(?:{c}[\\/])+(?:{c})?|[\\/]{c}", absolute_path, c = WINDOWS_COMPONENT)
+    };
+    Verex::new()
+        .find_expr(E::String(pattern.as_ref()))
+        .clone()
+}
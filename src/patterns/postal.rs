@@ -0,0 +1,53 @@
+use Expression as E;
+use Verex;
+
+/// The countries with known postal code formats, named by their ISO 3166-1 alpha-2 codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CountryCode {
+    /// Australia, e.g. `2000`
+    AU,
+    /// Brazil, e.g. `01310-100`
+    BR,
+    /// Canada, e.g. `K1A 0B1`
+    CA,
+    /// Germany, e.g. `10115`
+    DE,
+    /// France, e.g. `75008`
+    FR,
+    /// United Kingdom, e.g. `SW1A 1AA`
+    GB,
+    /// India, e.g. `110 001`
+    IN,
+    /// Japan, e.g. `100-0001`
+    JP,
+    /// Netherlands, e.g. `1012 AB`
+    NL,
+    /// United States, ZIP or ZIP+4, e.g. `12345-6789`
+    US
+}
+
+/// The postal code formats, kept as data so countries can be added without new code
+const POSTAL_CODES: &[(CountryCode, &str)] = &[
+    (CountryCode::AU, r"[0-9]{4}"),
+    (CountryCode::BR, r"[0-9]{5}\x2D?[0-9]{3}"),
+    (CountryCode::CA, r"[ABCEGHJ-NPRSTVXY][0-9][ABCEGHJ-NPRSTV-Z] ?[0-9][ABCEGHJ-NPRSTV-Z][0-9]"),
+    (CountryCode::DE, r"[0-9]{5}"),
+    (CountryCode::FR, r"[0-9]{5}"),
+    (CountryCode::GB, r"[A-Z]{1,2}[0-9][A-Z0-9]? ?[0-9][A-Z]{2}|GIR ?0AA"),
+    (CountryCode::IN, r"[1-9][0-9]{2} ?[0-9]{3}"),
+    (CountryCode::JP, r"[0-9]{3}\x2D[0-9]{4}"),
+    (CountryCode::NL, r"[1-9][0-9]{3} ?[A-Z]{2}"),
+    (CountryCode::US, r"[0-9]{5}(?:\x2D[0-9]{4})?"),
+];
+
+/// Find a postal code in the format of the country
+pub fn postal_code(country: CountryCode) -> Verex {
+    // every country has an entry in the table
+    let format = POSTAL_CODES.iter()
+        .find(|&&(code, _)| code == country)
+        .map(|&(_, format)| format)
+        .unwrap();
+    Verex::new()
+        .find_expr(E::String(format!(r"\b(?:{})\b", format).as_ref()))
+        .clone()
+}
@@ -0,0 +1,61 @@
+#[cfg(feature = "regex")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use Expression as E;
+use Verex;
+
+use super::named;
+
+/// The components of a URL found by `extract_url()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct UrlParts {
+    /// The scheme like `https`
+    pub scheme: String,
+    /// The host name or address, IPv6 addresses in brackets
+    pub host: String,
+    /// The port if one is given and fits into a `u16`
+    pub port: Option<u16>,
+    /// The path starting with `/`, or an empty string
+    pub path: String,
+    /// The query without the leading `?`
+    pub query: Option<String>,
+    /// The fragment without the leading `#`
+    pub fragment: Option<String>
+}
+
+/// Find a URL with an authority like `https://example.com:8080/a/b?c=d#e`
+///
+/// Captures `scheme`, `host`, `port`, `path`, `query` and `fragment`.
+pub fn url() -> Verex {
+    Verex::new()
+        .find_expr(E::String(named("scheme", r"[A-Za-z][A-Za-z0-9+.\x2D]*").as_ref()))
+        .find("://")
+        .find_expr(E::String(named("host", r"\[[0-9A-Fa-f:.]+\]|[^\s/?#:@\[\]]+").as_ref()))
+        .maybe_expr(E::String(format!(":{}", named("port", r"[0-9]{1,5}")).as_ref()))
+        .maybe_expr(E::String(named("path", r"/[^\s?#]*").as_ref()))
+        .maybe_expr(E::String(format!(r"\?{}", named("query", r"[^\s#]*")).as_ref()))
+        .maybe_expr(E::String(format!("#{}", named("fragment", r"\S*")).as_ref()))
+        .clone()
+}
+
+/// Extract the components of the first URL in the text
+#[cfg(feature = "regex")]
+pub fn extract_url(text: &str) -> Option<UrlParts> {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = REGEX.get_or_init(|| url().compile().unwrap());
+    let captures = regex.captures(text)?;
+    Some(UrlParts {
+        scheme: captures.name("scheme").unwrap_or("").to_owned(),
+        host: captures.name("host").unwrap_or("").to_owned(),
+        port: captures.name("port").and_then(|port| port.parse().ok()),
+        path: captures.name("path").unwrap_or("").to_owned(),
+        query: captures.name("query").map(str::to_owned),
+        fragment: captures.name("fragment").map(str::to_owned)
+    })
+}
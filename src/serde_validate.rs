@@ -0,0 +1,60 @@
+//! Validation of deserialized strings against verbal expressions
+//!
+//! Implement `ValidationPattern` for a marker type and use `matching` as the `deserialize_with`
+//! function of a field:
+//!
+//! ```ignore
+//! struct Year;
+//!
+//! impl ValidationPattern for Year {
+//!     fn verex() -> &'static Verex {
+//!         static VEREX: OnceLock<Verex> = OnceLock::new();
+//!         VEREX.get_or_init(|| Verex::new().start_of_line().digits(4).end_of_line().clone())
+//!     }
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Event {
+//!     #[serde(deserialize_with = "verex::serde_validate::matching::<Year, _>")]
+//!     year: String
+//! }
+//! ```
+
+use serde::de::{Deserialize, Deserializer, Error};
+
+use Verex;
+
+/// A pattern that deserialized values are validated against
+pub trait ValidationPattern {
+    /// The expression values have to match, kept in a static so it is only compiled once
+    fn verex() -> &'static Verex;
+}
+
+/// Check a deserialized value against the pattern
+fn validate<P: ValidationPattern, E: Error>(value: String) -> Result<String, E> {
+    let verex = P::verex();
+    let regex = verex.compile().map_err(|error| {
+        E::custom(format!("invalid pattern `{}`: {}", verex.source(), error))
+    })?;
+    if regex.is_match(value.as_ref()) {
+        Ok(value)
+    }
+    else {
+        Err(E::custom(format!("invalid value '{}': does not match `{}`", value, verex.source())))
+    }
+}
+
+/// Deserialize a string and fail unless it matches the pattern
+pub fn matching<'de, P, D>(deserializer: D) -> Result<String, D::Error>
+    where P: ValidationPattern, D: Deserializer<'de> {
+    validate::<P, D::Error>(String::deserialize(deserializer)?)
+}
+
+/// Deserialize an optional string and fail unless it is missing or matches the pattern
+pub fn matching_optional<'de, P, D>(deserializer: D) -> Result<Option<String>, D::Error>
+    where P: ValidationPattern, D: Deserializer<'de> {
+    match Option::<String>::deserialize(deserializer)? {
+        Some(value) => validate::<P, D::Error>(value).map(Some),
+        None => Ok(None),
+    }
+}
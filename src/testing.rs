@@ -0,0 +1,114 @@
+//! Helpers for testing code that builds or consumes verbal expressions
+//!
+//! `random_verex()` creates arbitrary but always compilable `Verex` values, e.g. for fuzz targets
+//! and property tests of escaping, compilation and round trips.
+
+use rand::Rng;
+
+use Expression;
+use Verex;
+
+/// Characters used for random literals, including all characters that need escaping
+const LITERAL_CHARS: &[char] = &[
+    'a', 'b', 'z', 'A', 'Z', '0', '9', ' ', '_', '-', '#', '\\', '(', ')', '[', ']', '{', '}',
+    '.', '+', '*', '?', '^', '$', '|', 'ä', 'ß', '€', '字', '😀',
+];
+
+/// Characters used for random character classes
+const CLASS_CHARS: &[char] = &[
+    'a', 'b', 'z', 'A', 'Z', '0', '9', ' ', '_', '-', '#', '\\', '(', ')', '[', ']', '{', '}',
+    '.', '+', '*', '?', '^', '$', '|', 'ä', '€', '😀',
+];
+
+/// Characters used for random ranges
+const RANGE_CHARS: &[char] = &['0', '5', '9', 'a', 'm', 'z', 'A', 'M', 'Z'];
+
+fn random_string<R: Rng>(rng: &mut R, chars: &[char]) -> String {
+    let len = rng.gen_range(1..5);
+    (0..len).map(|_| chars[rng.gen_range(0..chars.len())]).collect()
+}
+
+fn random_range<R: Rng>(rng: &mut R) -> (char, char) {
+    let from = rng.gen_range(0..RANGE_CHARS.len());
+    // the characters are grouped in ascending runs of three
+    let to = rng.gen_range(from..from - from % 3 + 3);
+    (RANGE_CHARS[from], RANGE_CHARS[to])
+}
+
+/// Add a random token and return whether it may be followed by a quantifier
+fn add_token<R: Rng>(rng: &mut R, verex: &mut Verex, depth: u32) -> bool {
+    let choices = if depth > 0 { 15 } else { 13 };
+    match rng.gen_range(0..choices) {
+        0 => { verex.find(random_string(rng, LITERAL_CHARS).as_ref()); true },
+        1 => { verex.any(random_string(rng, CLASS_CHARS).as_ref()); true },
+        2 => { verex.anything(); true },
+        3 => { verex.anything_but(random_string(rng, CLASS_CHARS).as_ref()); true },
+        4 => { verex.something(); true },
+        5 => { verex.something_but(random_string(rng, CLASS_CHARS).as_ref()); true },
+        6 => { verex.maybe(random_string(rng, LITERAL_CHARS).as_ref()); false },
+        7 => { verex.capture(random_string(rng, LITERAL_CHARS).as_ref()); true },
+        8 => { verex.digit(); true },
+        9 => { verex.word(); true },
+        10 => { verex.tab(); true },
+        11 => { verex.line_break(); true },
+        12 => {
+            let ranges = (0..rng.gen_range(1..3)).map(|_| random_range(rng)).collect();
+            verex.range(ranges);
+            true
+        },
+        13 => {
+            let inner = random_verex(rng, depth - 1);
+            verex.find_expr(Expression::Verex(&inner));
+            true
+        },
+        _ => {
+            let inner = random_verex(rng, depth - 1);
+            verex.capture_expr(Expression::Verex(&inner));
+            true
+        },
+    }
+}
+
+fn add_quantifier<R: Rng>(rng: &mut R, verex: &mut Verex) {
+    match rng.gen_range(0..6) {
+        0 => { verex.repeat_once_or_more(); },
+        1 => { verex.repeat_zero_or_more(); },
+        2 => { verex.repeat_n(rng.gen_range(0..4)); },
+        3 => {
+            let n = rng.gen_range(0..4);
+            verex.repeat_n_to_m(n, n + rng.gen_range(0..4));
+        },
+        _ => {},
+    }
+}
+
+/// Create a random `Verex` that always compiles
+///
+/// `depth` limits how deeply random sub-expressions are nested inside each other.
+pub fn random_verex<R: Rng>(rng: &mut R, depth: u32) -> Verex {
+    let mut verex = Verex::new();
+    if rng.gen_bool(0.2) {
+        verex.with_any_case(true);
+    }
+    if rng.gen_bool(0.2) {
+        verex.multi_line(true);
+    }
+    if rng.gen_bool(0.2) {
+        verex.tolerate_whitespace(true);
+    }
+    if rng.gen_bool(0.3) {
+        verex.start_of_line();
+    }
+    for i in 0..rng.gen_range(1..6) {
+        if i > 0 && rng.gen_bool(0.1) {
+            verex.or();
+        }
+        if add_token(rng, &mut verex, depth) {
+            add_quantifier(rng, &mut verex);
+        }
+    }
+    if rng.gen_bool(0.3) {
+        verex.end_of_line();
+    }
+    verex
+}
@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+use std::error;
+use std::fmt;
+
+use regex_automata::Anchored;
+use regex_automata::dfa::Automaton;
+use regex_automata::dfa::dense::{self, BuildError, DFA};
+use regex_automata::util::primitives::StateID;
+use regex_automata::util::start;
+
+/// The maximum heap size in bytes of each DFA built for an analysis
+const DFA_SIZE_LIMIT: usize = 10 * (1 << 20);
+
+/// The errors that can occur when analysing patterns
+#[derive(Debug)]
+pub enum AnalysisError {
+    /// A pattern could not be turned into a DFA, e.g. because it exceeds the size limit
+    Build(BuildError),
+    /// A pattern uses a construct the DFA can not decide for all inputs, e.g. a Unicode word boundary
+    Unsupported
+}
+
+impl fmt::Display for AnalysisError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AnalysisError::Build(ref error) => write!(f, "could not build DFA: {}", error),
+            AnalysisError::Unsupported => write!(f, "pattern can not be fully analysed with a DFA"),
+        }
+    }
+}
+
+impl error::Error for AnalysisError {}
+
+/// Build a DFA that only matches the whole text
+#[allow(clippy::result_large_err)]
+fn whole_text_dfa(source: &str) -> Result<DFA<Vec<u32>>, AnalysisError> {
+    dense::Builder::new()
+        .configure(DFA::config()
+                       .dfa_size_limit(Some(DFA_SIZE_LIMIT))
+                       .determinize_size_limit(Some(DFA_SIZE_LIMIT))
+                       .unicode_word_boundary(true))
+        .build(format!(r"\A(?:{})\z", source).as_ref())
+        .map_err(AnalysisError::Build)
+}
+
+#[allow(clippy::result_large_err)]
+fn start_state(dfa: &DFA<Vec<u32>>) -> Result<StateID, AnalysisError> {
+    dfa.start_state(&start::Config::new().anchored(Anchored::Yes))
+       .map_err(|_| AnalysisError::Unsupported)
+}
+
+/// Whether both patterns match exactly the same set of whole texts
+///
+/// Walks the product of both DFAs, so it visits at most as many state pairs as the product of their sizes.
+#[allow(clippy::result_large_err)]
+pub fn equivalent(first: &str, second: &str) -> Result<bool, AnalysisError> {
+    let first_dfa = whole_text_dfa(first)?;
+    let second_dfa = whole_text_dfa(second)?;
+    let start = (start_state(&first_dfa)?, start_state(&second_dfa)?);
+    let mut seen = HashSet::new();
+    seen.insert(start);
+    let mut pending = vec![start];
+    while let Some((first_state, second_state)) = pending.pop() {
+        if first_dfa.is_quit_state(first_state) || second_dfa.is_quit_state(second_state) {
+            return Err(AnalysisError::Unsupported);
+        }
+        let first_match = first_dfa.is_match_state(first_dfa.next_eoi_state(first_state));
+        let second_match = second_dfa.is_match_state(second_dfa.next_eoi_state(second_state));
+        if first_match != second_match {
+            return Ok(false);
+        }
+        for byte in 0..=255 {
+            let next = (first_dfa.next_state(first_state, byte), second_dfa.next_state(second_state, byte));
+            if first_dfa.is_dead_state(next.0) && second_dfa.is_dead_state(next.1) {
+                continue;
+            }
+            if seen.insert(next) {
+                pending.push(next);
+            }
+        }
+    }
+    Ok(true)
+}
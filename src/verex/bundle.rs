@@ -0,0 +1,172 @@
+use std::error;
+use std::fmt;
+use std::str;
+
+use regex_automata::Input;
+use regex_automata::MatchError;
+use regex_automata::dfa::Automaton;
+use regex_automata::dfa::dense::BuildError;
+use regex_automata::dfa::sparse::DFA;
+use regex_automata::util::wire::DeserializeError;
+
+use super::Verex;
+
+const MAGIC: &[u8] = b"verex-bundle\x00\x01";
+
+/// The errors that can occur when loading or using a `PatternBundle`
+#[derive(Debug)]
+pub enum BundleError {
+    /// The bytes do not start with the bundle header
+    InvalidHeader,
+    /// The bytes end in the middle of a pattern
+    Truncated,
+    /// A pattern name or source is not valid UTF-8
+    InvalidUtf8,
+    /// A serialized DFA could not be loaded
+    Deserialize(DeserializeError),
+    /// There is no pattern with the given name in the bundle
+    UnknownPattern(String),
+    /// The DFA gave up searching the text
+    Search(MatchError)
+}
+
+impl fmt::Display for BundleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BundleError::InvalidHeader => write!(f, "invalid pattern bundle header"),
+            BundleError::Truncated => write!(f, "pattern bundle is truncated"),
+            BundleError::InvalidUtf8 => write!(f, "pattern bundle contains invalid UTF-8"),
+            BundleError::Deserialize(ref error) => write!(f, "could not load DFA: {}", error),
+            BundleError::UnknownPattern(ref name) => write!(f, "no pattern named '{}' in bundle", name),
+            BundleError::Search(ref error) => write!(f, "search failed: {}", error),
+        }
+    }
+}
+
+impl error::Error for BundleError {}
+
+/// A pattern of a `PatternBundle`
+#[derive(Debug, Clone)]
+struct BundledPattern {
+    name: String,
+    source: String,
+    dfa: DFA<Vec<u8>>
+}
+
+/// A collection of named patterns with precompiled DFAs that can be serialized to a single blob
+/// and loaded again without invoking the regex compiler
+#[derive(Debug, Clone, Default)]
+pub struct PatternBundle {
+    patterns: Vec<BundledPattern>
+}
+
+/// Reads the length-prefixed fields of a serialized bundle
+struct Reader<'b> {
+    bytes: &'b [u8]
+}
+
+impl<'b> Reader<'b> {
+    fn field(&mut self) -> Result<&'b [u8], BundleError> {
+        if self.bytes.len() < 4 {
+            return Err(BundleError::Truncated);
+        }
+        let mut len_bytes = [0; 4];
+        len_bytes.copy_from_slice(&self.bytes[..4]);
+        let len = u32::from_le_bytes(len_bytes) as usize;
+        if self.bytes.len() - 4 < len {
+            return Err(BundleError::Truncated);
+        }
+        let field = &self.bytes[4..4 + len];
+        self.bytes = &self.bytes[4 + len..];
+        Ok(field)
+    }
+
+    fn str_field(&mut self) -> Result<&'b str, BundleError> {
+        str::from_utf8(self.field()?).map_err(|_| BundleError::InvalidUtf8)
+    }
+}
+
+fn push_field(bytes: &mut Vec<u8>, field: &[u8]) {
+    bytes.extend_from_slice(&(field.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(field);
+}
+
+impl PatternBundle {
+    /// Create an empty bundle
+    pub fn new() -> PatternBundle {
+        PatternBundle::default()
+    }
+
+    /// Compile the `Verex` to a DFA and add it under the given name, replacing a pattern of the same name
+    #[allow(clippy::result_large_err)]
+    pub fn add(&mut self, name: &str, verex: &Verex) -> Result<&mut PatternBundle, BuildError> {
+        let pattern = BundledPattern {
+            name: name.to_owned(),
+            source: verex.source().to_owned(),
+            dfa: verex.compile_sparse_dfa()?
+        };
+        match self.patterns.iter().position(|existing| existing.name == name) {
+            Some(index) => self.patterns[index] = pattern,
+            None => self.patterns.push(pattern),
+        }
+        Ok(self)
+    }
+
+    /// Load a bundle from bytes created by `to_bytes()`
+    pub fn from_bytes(bytes: &[u8]) -> Result<PatternBundle, BundleError> {
+        if !bytes.starts_with(MAGIC) {
+            return Err(BundleError::InvalidHeader);
+        }
+        let mut reader = Reader { bytes: &bytes[MAGIC.len()..] };
+        let mut bundle = PatternBundle::new();
+        while !reader.bytes.is_empty() {
+            let name = reader.str_field()?;
+            let source = reader.str_field()?;
+            let dfa = DFA::from_bytes(reader.field()?).map_err(BundleError::Deserialize)?.0;
+            bundle.patterns.push(BundledPattern {
+                name: name.to_owned(),
+                source: source.to_owned(),
+                dfa: dfa.to_owned()
+            });
+        }
+        Ok(bundle)
+    }
+
+    /// Serialize the bundle including the compiled DFAs
+    pub fn to_bytes(& self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        for pattern in &self.patterns {
+            push_field(&mut bytes, pattern.name.as_bytes());
+            push_field(&mut bytes, pattern.source.as_bytes());
+            push_field(&mut bytes, pattern.dfa.to_bytes_little_endian().as_ref());
+        }
+        bytes
+    }
+
+    /// Return the DFA of the pattern with the given name
+    pub fn dfa(& self, name: &str) -> Option<&DFA<Vec<u8>>> {
+        self.find_pattern(name).map(|pattern| &pattern.dfa)
+    }
+
+    fn find_pattern(& self, name: &str) -> Option<&BundledPattern> {
+        self.patterns.iter().find(|pattern| pattern.name == name)
+    }
+
+    /// Test whether the pattern with the given name matches somewhere in the text
+    pub fn is_match(& self, name: &str, text: &str) -> Result<bool, BundleError> {
+        let pattern = self.find_pattern(name).ok_or_else(|| BundleError::UnknownPattern(name.to_owned()))?;
+        pattern.dfa.try_search_fwd(&Input::new(text))
+                   .map(|found| found.is_some())
+                   .map_err(BundleError::Search)
+    }
+
+    /// Return the names of the patterns in the order they were added
+    pub fn names(& self) -> Vec<&str> {
+        self.patterns.iter().map(|pattern| pattern.name.as_ref()).collect()
+    }
+
+    /// Return the regex source of the pattern with the given name
+    pub fn source(& self, name: &str) -> Option<&str> {
+        self.find_pattern(name).map(|pattern| pattern.source.as_ref())
+    }
+}
@@ -0,0 +1,98 @@
+use std::panic::Location;
+
+/// Information about the builder call that created a capturing group
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptureStep {
+    /// The index of the capturing group in the compiled regex
+    pub index: usize,
+    /// The generated name of the group if automatic capture naming was enabled
+    pub name: Option<String>,
+    /// The source location the capturing method was called from
+    pub location: &'static Location<'static>
+}
+
+/// Whether the group opened at the start of the given characters is a capturing group
+fn is_capturing_group(group: &[char]) -> bool {
+    match group {
+        ['(', '?', 'P', '<', ..] => true,
+        ['(', '?', '<', next, ..] => *next != '=' && *next != '!',
+        ['(', '?', ..] => false,
+        _ => true,
+    }
+}
+
+/// Find the character indices of the opening parentheses of all capturing groups in a regex string
+fn capture_group_starts(chars: &[char]) -> Vec<usize> {
+    let mut starts = Vec::new();
+    let mut in_class = false;
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => {
+                i += 1;
+            },
+            // skip ASCII classes like [:alpha:]
+            '[' if in_class && chars.get(i + 1) == Some(&':') => {
+                while i + 1 < chars.len() && !(chars[i] == ':' && chars[i + 1] == ']') {
+                    i += 1;
+                }
+                i += 1;
+            },
+            '[' if !in_class => {
+                in_class = true;
+                if chars.get(i + 1) == Some(&'^') {
+                    i += 1;
+                }
+                // a closing bracket right at the start is a literal
+                if chars.get(i + 1) == Some(&']') {
+                    i += 1;
+                }
+            },
+            ']' if in_class => {
+                in_class = false;
+            },
+            '(' if !in_class && is_capturing_group(&chars[i..]) => {
+                starts.push(i);
+            },
+            _ => {},
+        }
+        i += 1;
+    }
+    starts
+}
+
+/// Count the capturing groups (numbered and named) in a regex string
+pub fn count_capture_groups(pattern: &str) -> usize {
+    let chars: Vec<char> = pattern.chars().collect();
+    capture_group_starts(&chars).len()
+}
+
+/// Replace the opening parenthesis (with the name) of every capturing group of a regex string
+/// by the string returned for the name of the group, or `None` for numbered groups
+pub fn map_capture_groups<F>(pattern: &str, mut opening: F) -> String where F: FnMut(Option<&str>) -> String {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut result = String::with_capacity(pattern.len());
+    let mut last = 0;
+    for start in capture_group_starts(&chars) {
+        result.extend(&chars[last..start]);
+        if chars.get(start + 1) == Some(&'?') {
+            // skip the name of the group
+            let end = start + chars[start..].iter().position(|&c| c == '>').map(|end| end + 1).unwrap_or(1);
+            let name_start = start + chars[start..end].iter().position(|&c| c == '<').map(|i| i + 1).unwrap_or(1);
+            let name: String = chars[name_start..end - 1].iter().collect();
+            result.push_str(opening(Some(name.as_ref())).as_ref());
+            last = end;
+        }
+        else {
+            result.push_str(opening(None).as_ref());
+            last = start + 1;
+        }
+    }
+    result.extend(&chars[last..]);
+    result
+}
+
+/// Turn all capturing groups (numbered and named) of a regex string into non-capturing groups
+pub fn strip_capture_groups(pattern: &str) -> String {
+    map_capture_groups(pattern, |_| r"(?:".to_owned())
+}
@@ -0,0 +1,65 @@
+use std::ops::RangeInclusive;
+
+use super::escape_class_char;
+
+/// A set of characters for `Verex::any_set()`, built from single characters, ranges and a negation
+///
+/// Unlike the string of `any()` nothing has a special meaning, so `-` and `^` are always literal characters.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CharSet {
+    chars: Vec<char>,
+    ranges: Vec<(char, char)>,
+    negated: bool
+}
+
+impl CharSet {
+    /// Create an empty set
+    pub fn new() -> CharSet {
+        CharSet::default()
+    }
+
+    /// Add the characters, e.g. `"+-".chars()`
+    pub fn chars<I: IntoIterator<Item = char>>(&mut self, chars: I) -> &mut CharSet {
+        self.chars.extend(chars);
+        self
+    }
+
+    /// Add all characters of the range, e.g. `'a'..='z'`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, i.e. its start is after its end.
+    #[track_caller]
+    pub fn range(&mut self, range: RangeInclusive<char>) -> &mut CharSet {
+        let (from, to) = range.into_inner();
+        assert!(from <= to, "invalid range '{}'..='{}'", from, to);
+        self.ranges.push((from, to));
+        self
+    }
+
+    /// Match all characters except the ones in the set
+    pub fn negate(&mut self, negated: bool) -> &mut CharSet {
+        self.negated = negated;
+        self
+    }
+
+    /// Whether no characters were added to the set
+    pub fn is_empty(& self) -> bool {
+        self.chars.is_empty() && self.ranges.is_empty()
+    }
+
+    /// Render the set as character class
+    pub(crate) fn to_class(& self) -> String {
+        let mut class = if self.negated { r"[^".to_owned() } else { r"[".to_owned() };
+        for &c in &self.chars {
+            class.push_str(escape_class_char(c).as_ref());
+        }
+        for &(from, to) in &self.ranges {
+            class.push_str(escape_class_char(from).as_ref());
+            class.push('-');
+            class.push_str(escape_class_char(to).as_ref());
+        }
+        class.push(']');
+        class
+    }
+}
@@ -0,0 +1,131 @@
+use std::borrow::Cow;
+
+use super::escape;
+
+/// The line breaks matched by `line_break()` and `br()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineBreak {
+    /// Both `\n` and `\r\n`
+    Any,
+    /// Only `\n`
+    Unix,
+    /// Only `\r\n`
+    Windows
+}
+
+impl LineBreak {
+    /// The regex string matching the line break
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            LineBreak::Any => r"\n|(?:\r\n)",
+            LineBreak::Unix => r"\n",
+            LineBreak::Windows => r"\r\n",
+        }
+    }
+}
+
+/// Settings applied to all builder methods of a `Verex` created with `Verex::with_config()`
+///
+/// This allows standardizing the style of patterns in one place.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerexConfig {
+    /// Whether quantifiers wrap the previous token in a group if they would otherwise only apply to a part of it
+    pub group_before_quantifiers: bool,
+    /// Whether `anything()` and `something()` add capturing groups
+    pub capture_wildcards: bool,
+    /// Whether groups added by `capture()` get generated names (`cap_1`, `cap_2`, ...)
+    pub auto_capture: bool,
+    /// Whether values of methods like `find()` and `any()` are escaped, otherwise they are used as regex
+    pub escape_values: bool,
+    /// Whether `digit()` and the word methods match Unicode characters or only ASCII
+    pub unicode: bool,
+    /// The line breaks matched by `line_break()`
+    pub line_break: LineBreak
+}
+
+impl Default for VerexConfig {
+    fn default() -> VerexConfig {
+        VerexConfig {
+            group_before_quantifiers: true,
+            capture_wildcards: true,
+            auto_capture: false,
+            escape_values: true,
+            unicode: true,
+            line_break: LineBreak::Any
+        }
+    }
+}
+
+/// Limits for `Verex::compile_with()`, e.g. to allow large generated alternations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompileOptions {
+    /// The maximum size of the compiled program in bytes
+    pub size_limit: usize,
+    /// The maximum size of the cache of the lazy DFA in bytes
+    pub dfa_size_limit: usize
+}
+
+impl Default for CompileOptions {
+    /// The limits `compile()` uses
+    fn default() -> CompileOptions {
+        CompileOptions {
+            size_limit: 10 * (1 << 20),
+            dfa_size_limit: 2 * (1 << 20)
+        }
+    }
+}
+
+/// How `with_any_case()` makes an expression case-insensitive, set with `Verex::fold_case()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CaseFolding {
+    /// The `i` flag of the regex engine, which uses simple Unicode case folding
+    #[default]
+    Simple,
+    /// Only ASCII letters are expanded to classes like `[aA]`, other letters match case-sensitively
+    Ascii,
+    /// Letters are expanded to all their case variants, including full case foldings
+    /// like `ß` to `ss`, for dialects and engines where the `i` flag is unreliable
+    Full
+}
+
+/// How `Verex::find_with()` treats the characters of a value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EscapeMode {
+    /// All characters match literally
+    #[default]
+    Literal,
+    /// `*` matches any characters and `?` a single one like in a search box, all other characters match literally
+    SmartWildcards,
+    /// The value is used as regex as is
+    None
+}
+
+impl EscapeMode {
+    /// Turn the value into a regex string
+    pub(crate) fn apply(self, value: &str) -> Cow<'_, str> {
+        match self {
+            EscapeMode::Literal => escape(value),
+            EscapeMode::SmartWildcards => {
+                let mut result = String::with_capacity(value.len() * 2);
+                for c in value.chars() {
+                    match c {
+                        '*' => result.push_str(".*"),
+                        '?' => result.push('.'),
+                        _ => result.push_str(escape(c.encode_utf8(&mut [0; 4])).as_ref()),
+                    }
+                }
+                Cow::Owned(result)
+            },
+            EscapeMode::None => Cow::Borrowed(value),
+        }
+    }
+}
+
+/// How `Verex::from_parts()` joins the expressions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinPolicy {
+    /// The expressions have to match one after the other
+    Sequence,
+    /// Any one of the expressions has to match
+    Alternation
+}
@@ -0,0 +1,324 @@
+use std::error;
+use std::fmt;
+
+#[cfg(feature = "regex")]
+use regex::Error;
+#[cfg(not(feature = "regex"))]
+use regex_syntax::Error;
+use regex_syntax::{CharClass, ClassRange, Expr, Repeater};
+
+use super::normalize::simplify;
+
+/// The errors that can occur when rendering an expression for another regex dialect
+#[derive(Debug)]
+pub enum ExportError {
+    /// The expression is not a valid regex
+    Syntax(Error),
+    /// The expression uses a construct the dialect can not express
+    Unsupported(String)
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ExportError::Syntax(ref error) => write!(f, "{}", error),
+            ExportError::Unsupported(ref construct) => write!(f, "{} is not supported by the dialect", construct),
+        }
+    }
+}
+
+impl error::Error for ExportError {}
+
+/// The regex dialects an expression can be rendered for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    /// POSIX extended regular expressions
+    PosixEre,
+    /// The SQL `SIMILAR TO` operator, which always matches the whole text
+    SimilarTo,
+    /// Basic regular expressions with the GNU extensions as used by `grep` and `sed`
+    GnuBre,
+    /// Extended regular expressions with the GNU extensions as used by `grep -E` and `sed -E`
+    GnuEre
+}
+
+impl Dialect {
+    /// The operator characters that are escaped with a backslash in basic regular expressions
+    fn operator(self, operator: &str) -> String {
+        match self {
+            Dialect::GnuBre => format!("\\{}", operator),
+            _ => operator.to_owned(),
+        }
+    }
+
+    /// Whether the dialect supports the GNU extensions like word boundaries
+    fn is_gnu(self) -> bool {
+        self == Dialect::GnuBre || self == Dialect::GnuEre
+    }
+}
+
+fn unsupported<T>(construct: &str) -> Result<T, ExportError> {
+    Err(ExportError::Unsupported(construct.to_owned()))
+}
+
+/// Parse a regex source and render it for the dialect
+pub fn render(source: &str, dialect: Dialect) -> Result<String, ExportError> {
+    #[cfg(feature = "regex")]
+    let expr = Expr::parse(source).map_err(|error| ExportError::Syntax(Error::Syntax(error)))?;
+    #[cfg(not(feature = "regex"))]
+    let expr = Expr::parse(source).map_err(ExportError::Syntax)?;
+    let expr = simplify(expr);
+    let mut result = String::new();
+    match dialect {
+        Dialect::SimilarTo => render_similar_to(expr, &mut result)?,
+        _ => render_expr(&expr, dialect, &mut result)?,
+    }
+    Ok(result)
+}
+
+/// Render the top level of a `SIMILAR TO` pattern, turning leading and trailing anchors into
+/// the absence of `%` wildcards
+fn render_similar_to(expr: Expr, result: &mut String) -> Result<(), ExportError> {
+    let mut es = match expr {
+        Expr::Concat(es) => es,
+        e => vec![e],
+    };
+    let anchored_start = es.first() == Some(&Expr::StartText);
+    if anchored_start {
+        es.remove(0);
+    }
+    let anchored_end = es.last() == Some(&Expr::EndText);
+    if anchored_end {
+        es.pop();
+    }
+    if !anchored_start {
+        result.push('%');
+    }
+    let expr = match es.len() {
+        0 => Expr::Empty,
+        1 => es.pop().unwrap(),
+        _ => Expr::Concat(es),
+    };
+    match expr {
+        // `%` must not become part of the alternatives
+        Expr::Alternate(_) if !(anchored_start && anchored_end) => render_atom(&expr, Dialect::SimilarTo, result)?,
+        _ => render_expr(&expr, Dialect::SimilarTo, result)?,
+    }
+    if !anchored_end {
+        result.push('%');
+    }
+    Ok(())
+}
+
+/// Characters with a special meaning outside of bracket expressions
+fn special_chars(dialect: Dialect) -> &'static str {
+    match dialect {
+        Dialect::PosixEre | Dialect::GnuEre => r".[\()*+?{}|^$",
+        Dialect::GnuBre => r".[\*^$",
+        Dialect::SimilarTo => r"_%[]\()*+?{}|^$",
+    }
+}
+
+fn render_char(c: char, dialect: Dialect, result: &mut String) {
+    if special_chars(dialect).contains(c) {
+        result.push('\\');
+    }
+    result.push(c);
+}
+
+/// Render a letter of a case insensitive literal as a bracket expression of both cases
+fn render_char_any_case(c: char, dialect: Dialect, result: &mut String) {
+    let lower: Vec<char> = c.to_lowercase().collect();
+    let upper: Vec<char> = c.to_uppercase().collect();
+    if lower.len() == 1 && upper.len() == 1 && lower[0] != upper[0] {
+        result.push('[');
+        result.push(lower[0]);
+        result.push(upper[0]);
+        result.push(']');
+    }
+    else {
+        render_char(c, dialect, result);
+    }
+}
+
+/// Named classes for the classes of the Perl shorthands
+fn named_class(class: &CharClass) -> Option<&'static str> {
+    let names = [
+        (r"\d", "[[:digit:]]"), (r"\D", "[^[:digit:]]"), (r"(?-u:\d)", "[[:digit:]]"), (r"(?-u:\D)", "[^[:digit:]]"),
+        (r"\w", "[[:alnum:]_]"), (r"\W", "[^[:alnum:]_]"), (r"(?-u:\w)", "[[:alnum:]_]"), (r"(?-u:\W)", "[^[:alnum:]_]"),
+        (r"\s", "[[:space:]]"), (r"\S", "[^[:space:]]"), (r"(?-u:\s)", "[[:space:]]"), (r"(?-u:\S)", "[^[:space:]]"),
+    ];
+    for &(shorthand, name) in names.iter() {
+        if let Ok(Expr::Class(ref known)) = Expr::parse(shorthand) {
+            if known == class {
+                return Some(name);
+            }
+        }
+    }
+    None
+}
+
+/// Split the ranges so that the characters with a position dependent meaning in bracket
+/// expressions are single characters
+fn split_ranges(ranges: &CharClass) -> (Vec<ClassRange>, Vec<char>) {
+    let mut result = Vec::new();
+    let mut specials = Vec::new();
+    for range in ranges.iter() {
+        let mut start = range.start;
+        for &special in ['-', ']', '^'].iter() {
+            if special < start || special > range.end {
+                continue;
+            }
+            specials.push(special);
+            if special > start {
+                result.push(ClassRange { start, end: (special as u8 - 1) as char });
+            }
+            start = (special as u8 + 1) as char;
+        }
+        if start <= range.end {
+            result.push(ClassRange { start, end: range.end });
+        }
+    }
+    (result, specials)
+}
+
+/// Add a character to a bracket expression
+fn push_class_char(c: char, dialect: Dialect, result: &mut String) {
+    // SQL implementations translate to regex flavors that allow escapes in brackets
+    if c == '\\' && dialect == Dialect::SimilarTo {
+        result.push('\\');
+    }
+    result.push(c);
+}
+
+/// Render a class as bracket expression
+fn render_class(class: &CharClass, dialect: Dialect, result: &mut String) {
+    if let Some(name) = named_class(class) {
+        result.push_str(name);
+        return;
+    }
+    let negated = class.first().map(|range| range.start) == Some('\0')
+                  && class.last().map(|range| range.end) == Some('\u{10FFFF}');
+    let ranges = if negated { class.clone().negate() } else { class.clone() };
+    let (ranges, specials) = split_ranges(&ranges);
+    // `]` has to come first, `^` must not come first and `-` has to come first or last
+    let lone_circumflex = !negated && ranges.is_empty() && specials == ['^'];
+    if lone_circumflex {
+        render_char('^', dialect, result);
+        return;
+    }
+    result.push('[');
+    if negated {
+        result.push('^');
+    }
+    let circumflex_first = !negated && ranges.is_empty() && specials == ['-', '^'];
+    if specials.contains(&']') {
+        result.push(']');
+    }
+    if circumflex_first {
+        result.push_str("-^]");
+        return;
+    }
+    for range in &ranges {
+        push_class_char(range.start, dialect, result);
+        if range.end != range.start {
+            result.push('-');
+            push_class_char(range.end, dialect, result);
+        }
+    }
+    if specials.contains(&'^') {
+        result.push('^');
+    }
+    if specials.contains(&'-') {
+        result.push('-');
+    }
+    result.push(']');
+}
+
+fn render_repeater(repeater: &Repeater, dialect: Dialect, result: &mut String) {
+    let bounds = match *repeater {
+        Repeater::ZeroOrOne => return result.push_str(dialect.operator("?").as_ref()),
+        Repeater::ZeroOrMore => return result.push('*'),
+        Repeater::OneOrMore => return result.push_str(dialect.operator("+").as_ref()),
+        Repeater::Range { min, max: None } => format!("{},", min),
+        Repeater::Range { min, max: Some(max) } if min == max => format!("{}", min),
+        Repeater::Range { min, max: Some(max) } => format!("{},{}", min, max),
+    };
+    result.push_str(dialect.operator("{").as_ref());
+    result.push_str(bounds.as_ref());
+    result.push_str(dialect.operator("}").as_ref());
+}
+
+/// Render an expression wrapped in a group if it is not a single item
+fn render_atom(expr: &Expr, dialect: Dialect, result: &mut String) -> Result<(), ExportError> {
+    let needs_group = match *expr {
+        Expr::Literal { ref chars, .. } => chars.len() != 1,
+        Expr::Empty | Expr::Concat(_) | Expr::Alternate(_) | Expr::Repeat { .. } => true,
+        _ => false,
+    };
+    if needs_group {
+        result.push_str(dialect.operator("(").as_ref());
+        render_expr(expr, dialect, result)?;
+        result.push_str(dialect.operator(")").as_ref());
+        Ok(())
+    }
+    else {
+        render_expr(expr, dialect, result)
+    }
+}
+
+fn render_expr(expr: &Expr, dialect: Dialect, result: &mut String) -> Result<(), ExportError> {
+    match *expr {
+        Expr::Empty => {},
+        Expr::Literal { ref chars, casei } => {
+            for &c in chars {
+                if casei {
+                    render_char_any_case(c, dialect, result);
+                }
+                else {
+                    render_char(c, dialect, result);
+                }
+            }
+        },
+        Expr::AnyChar | Expr::AnyCharNoNL => match dialect {
+            Dialect::SimilarTo => result.push('_'),
+            _ => result.push('.'),
+        },
+        Expr::Class(ref class) => render_class(class, dialect, result),
+        Expr::StartLine | Expr::StartText if dialect != Dialect::SimilarTo => result.push('^'),
+        Expr::EndLine | Expr::EndText if dialect != Dialect::SimilarTo => result.push('$'),
+        Expr::StartLine | Expr::StartText | Expr::EndLine | Expr::EndText => return unsupported("an anchor inside the pattern"),
+        Expr::WordBoundary | Expr::WordBoundaryAscii if dialect.is_gnu() => result.push_str(r"\b"),
+        Expr::NotWordBoundary | Expr::NotWordBoundaryAscii if dialect.is_gnu() => result.push_str(r"\B"),
+        Expr::WordBoundary | Expr::WordBoundaryAscii => return unsupported("a word boundary"),
+        Expr::NotWordBoundary | Expr::NotWordBoundaryAscii => return unsupported("a word boundary"),
+        Expr::Group { ref e, .. } => {
+            result.push_str(dialect.operator("(").as_ref());
+            render_expr(e, dialect, result)?;
+            result.push_str(dialect.operator(")").as_ref());
+        },
+        Expr::Repeat { greedy: false, .. } => return unsupported("a lazy quantifier"),
+        Expr::Repeat { ref e, ref r, .. } => {
+            render_atom(e, dialect, result)?;
+            render_repeater(r, dialect, result);
+        },
+        Expr::Concat(ref es) => {
+            for e in es {
+                match *e {
+                    Expr::Alternate(_) => render_atom(e, dialect, result)?,
+                    _ => render_expr(e, dialect, result)?,
+                }
+            }
+        },
+        Expr::Alternate(ref es) => {
+            for (i, e) in es.iter().enumerate() {
+                if i > 0 {
+                    result.push_str(dialect.operator("|").as_ref());
+                }
+                render_expr(e, dialect, result)?;
+            }
+        },
+        _ => return unsupported("matching bytes"),
+    }
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+
+/// The change of one logical part between two expressions in a `VerexDiff`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartDiff {
+    /// A part both expressions have
+    Unchanged(String),
+    /// A part only the new expression has
+    Added(String),
+    /// A part only the old expression has
+    Removed(String),
+    /// A part of the old expression that was replaced by one of the new expression
+    Changed {
+        /// The part of the old expression
+        old: String,
+        /// The part of the new expression
+        new: String
+    }
+}
+
+/// The difference between two expressions as returned by `Verex::diff()`, compared part by part
+/// as the parts were added by the builder methods
+///
+/// The `Display` implementation renders one line per part, prefixed with `+`, `-` or `~` if it changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerexDiff {
+    /// The flag groups of both sources, e.g. `(?:` and `(?i:`, if they differ
+    pub flags: Option<(String, String)>,
+    /// The parts of both expressions in order
+    pub parts: Vec<PartDiff>
+}
+
+impl VerexDiff {
+    /// Whether both expressions are the same
+    pub fn is_empty(& self) -> bool {
+        self.flags.is_none() && self.parts.iter().all(|part| matches!(*part, PartDiff::Unchanged(_)))
+    }
+}
+
+impl fmt::Display for VerexDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some((ref old, ref new)) = self.flags {
+            writeln!(f, "~ flags {} -> {}", old, new)?;
+        }
+        for part in &self.parts {
+            match *part {
+                PartDiff::Unchanged(ref string) => writeln!(f, "  {}", string)?,
+                PartDiff::Added(ref string) => writeln!(f, "+ {}", string)?,
+                PartDiff::Removed(ref string) => writeln!(f, "- {}", string)?,
+                PartDiff::Changed { ref old, ref new } => writeln!(f, "~ {} -> {}", old, new)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Diff two lists of parts by their longest common subsequence, pairing runs of removed parts
+/// with the runs of added parts that directly follow them as changes
+pub fn diff_parts(old: &[&str], new: &[&str]) -> Vec<PartDiff> {
+    // lengths[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let mut lengths = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            }
+            else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::new();
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            pair_changes(&mut result, &mut removed, &mut added);
+            result.push(PartDiff::Unchanged(old[i].to_owned()));
+            i += 1;
+            j += 1;
+        }
+        else if i < old.len() && (j == new.len() || lengths[i + 1][j] >= lengths[i][j + 1]) {
+            if !added.is_empty() {
+                pair_changes(&mut result, &mut removed, &mut added);
+            }
+            removed.push(old[i].to_owned());
+            i += 1;
+        }
+        else {
+            added.push(new[j].to_owned());
+            j += 1;
+        }
+    }
+    pair_changes(&mut result, &mut removed, &mut added);
+    result
+}
+
+/// Move a run of removed parts and the following run of added parts to the result
+fn pair_changes(result: &mut Vec<PartDiff>, removed: &mut Vec<String>, added: &mut Vec<String>) {
+    let mut removed = removed.drain(..);
+    let mut added = added.drain(..);
+    loop {
+        match (removed.next(), added.next()) {
+            (Some(old), Some(new)) => result.push(PartDiff::Changed { old, new }),
+            (Some(old), None) => result.push(PartDiff::Removed(old)),
+            (None, Some(new)) => result.push(PartDiff::Added(new)),
+            (None, None) => return,
+        }
+    }
+}
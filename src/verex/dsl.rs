@@ -0,0 +1,264 @@
+use std::error;
+use std::fmt;
+
+use super::Verex;
+
+/// What went wrong while parsing the builder DSL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DslErrorKind {
+    /// A word that is not the name of a builder method
+    UnknownCommand(String),
+    /// A quoted string without a closing quote
+    UnterminatedString,
+    /// A character that can not start a word, string or number
+    UnexpectedCharacter(char),
+    /// The input ended before all arguments of a command were given
+    MissingArgument {
+        /// The command missing the argument
+        command: String,
+        /// A description of the expected argument
+        expected: &'static str
+    },
+    /// An argument of the wrong type was given
+    InvalidArgument {
+        /// The command the argument was given to
+        command: String,
+        /// A description of the expected argument
+        expected: &'static str
+    }
+}
+
+/// An error with its location in the DSL text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DslError {
+    /// What went wrong
+    pub kind: DslErrorKind,
+    /// The byte offset of the error
+    pub position: usize,
+    /// The line of the error, starting at 1
+    pub line: usize,
+    /// The column (in characters) of the error, starting at 1
+    pub column: usize
+}
+
+impl DslError {
+    fn new(dsl: &str, position: usize, kind: DslErrorKind) -> DslError {
+        let before = &dsl[..position];
+        let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+        DslError {
+            kind,
+            position,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1
+        }
+    }
+}
+
+impl fmt::Display for DslErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DslErrorKind::UnknownCommand(ref command) => write!(f, "unknown command '{}'", command),
+            DslErrorKind::UnterminatedString => write!(f, "unterminated string"),
+            DslErrorKind::UnexpectedCharacter(c) => write!(f, "unexpected character '{}'", c),
+            DslErrorKind::MissingArgument { ref command, expected } =>
+                write!(f, "'{}' is missing an argument, expected {}", command, expected),
+            DslErrorKind::InvalidArgument { ref command, expected } =>
+                write!(f, "invalid argument for '{}', expected {}", command, expected),
+        }
+    }
+}
+
+impl fmt::Display for DslError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at line {}, column {}", self.kind, self.line, self.column)
+    }
+}
+
+impl error::Error for DslError {}
+
+#[derive(Debug)]
+enum Token {
+    Word(String),
+    Str(String),
+    Number(u32)
+}
+
+fn tokenize(dsl: &str) -> Result<Vec<(usize, Token)>, DslError> {
+    let mut tokens = Vec::new();
+    let mut chars = dsl.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        }
+        else if c == '\'' || c == '"' {
+            chars.next();
+            let mut string = String::new();
+            loop {
+                match chars.next() {
+                    Some((_, '\\')) => match chars.next() {
+                        Some((_, escaped)) => string.push(escaped),
+                        None => return Err(DslError::new(dsl, start, DslErrorKind::UnterminatedString)),
+                    },
+                    Some((_, quote)) if quote == c => break,
+                    Some((_, other)) => string.push(other),
+                    None => return Err(DslError::new(dsl, start, DslErrorKind::UnterminatedString)),
+                }
+            }
+            tokens.push((start, Token::Str(string)));
+        }
+        else if c.is_ascii_digit() {
+            let mut end = start;
+            while let Some(&(index, digit)) = chars.peek() {
+                if !digit.is_ascii_digit() {
+                    break;
+                }
+                end = index + 1;
+                chars.next();
+            }
+            let number = dsl[start..end].parse().map_err(|_| {
+                DslError::new(dsl, start, DslErrorKind::UnexpectedCharacter(c))
+            })?;
+            tokens.push((start, Token::Number(number)));
+        }
+        else if c.is_alphabetic() || c == '_' {
+            let mut word = String::new();
+            while let Some(&(_, letter)) = chars.peek() {
+                if !(letter.is_alphanumeric() || letter == '_') {
+                    break;
+                }
+                word.push(letter);
+                chars.next();
+            }
+            tokens.push((start, Token::Word(word)));
+        }
+        else {
+            return Err(DslError::new(dsl, start, DslErrorKind::UnexpectedCharacter(c)));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Reads the arguments of commands from the tokens
+struct Parser<'d> {
+    dsl: &'d str,
+    tokens: Vec<(usize, Token)>,
+    next: usize
+}
+
+impl<'d> Parser<'d> {
+    fn argument(&mut self, command: &str, expected: &'static str) -> Result<&Token, DslError> {
+        match self.tokens.get(self.next) {
+            Some((_, token)) => {
+                self.next += 1;
+                Ok(token)
+            },
+            None => Err(DslError::new(self.dsl, self.dsl.len(), DslErrorKind::MissingArgument {
+                command: command.to_owned(),
+                expected
+            })),
+        }
+    }
+
+    fn invalid(&self, command: &str, expected: &'static str) -> DslError {
+        DslError::new(self.dsl, self.tokens[self.next - 1].0, DslErrorKind::InvalidArgument {
+            command: command.to_owned(),
+            expected
+        })
+    }
+
+    fn string(&mut self, command: &str) -> Result<String, DslError> {
+        match *self.argument(command, "a quoted string")? {
+            Token::Str(ref string) => Ok(string.clone()),
+            _ => Err(self.invalid(command, "a quoted string")),
+        }
+    }
+
+    fn number(&mut self, command: &str) -> Result<u32, DslError> {
+        match *self.argument(command, "a number")? {
+            Token::Number(number) => Ok(number),
+            _ => Err(self.invalid(command, "a number")),
+        }
+    }
+
+    fn boolean(&mut self, command: &str) -> Result<bool, DslError> {
+        match *self.argument(command, "true or false")? {
+            Token::Word(ref word) if word == "true" => Ok(true),
+            Token::Word(ref word) if word == "false" => Ok(false),
+            _ => Err(self.invalid(command, "true or false")),
+        }
+    }
+}
+
+/// Build a `Verex` from whitespace separated builder method names and their arguments
+pub fn parse(dsl: &str) -> Result<Verex, DslError> {
+    let mut parser = Parser { dsl, tokens: tokenize(dsl)?, next: 0 };
+    let mut verex = Verex::new();
+    while parser.next < parser.tokens.len() {
+        let (start, command) = match parser.tokens[parser.next] {
+            (start, Token::Word(ref word)) => (start, word.clone()),
+            (start, _) => return Err(DslError::new(dsl, start, DslErrorKind::UnknownCommand(
+                dsl[start..].split_whitespace().next().unwrap_or("").to_owned()
+            ))),
+        };
+        parser.next += 1;
+        let command = command.as_ref();
+        match command {
+            "anything" => { verex.anything(); },
+            "br" => { verex.br(); },
+            "carriage_return" => { verex.carriage_return(); },
+            "control_char" => { verex.control_char(); },
+            "digit" => { verex.digit(); },
+            "digit_unicode" => { verex.digit_unicode(); },
+            "end_of_line" => { verex.end_of_line(); },
+            "line_break" => { verex.line_break(); },
+            "maybe_digit" => { verex.maybe_digit(); },
+            "maybe_whitespace" => { verex.maybe_whitespace(); },
+            "maybe_word" => { verex.maybe_word(); },
+            "newline" => { verex.newline(); },
+            "non_printable" => { verex.non_printable(); },
+            "or" => { verex.or(); },
+            "printable" => { verex.printable(); },
+            "punctuation" => { verex.punctuation(); },
+            "punctuation_ascii" => { verex.punctuation_ascii(); },
+            "repeat_once_or_more" => { verex.repeat_once_or_more(); },
+            "repeat_zero_or_more" => { verex.repeat_zero_or_more(); },
+            "something" => { verex.something(); },
+            "space" => { verex.space(); },
+            "start_of_line" => { verex.start_of_line(); },
+            "symbol" => { verex.symbol(); },
+            "symbol_ascii" => { verex.symbol_ascii(); },
+            "tab" => { verex.tab(); },
+            "whole_word" => { verex.whole_word(); },
+            "word" => { verex.word(); },
+            "word_char" => { verex.word_char(); },
+            "any" | "any_of" => { verex.any(parser.string(command)?.as_ref()); },
+            "anything_but" => { verex.anything_but(parser.string(command)?.as_ref()); },
+            "capture" => { verex.capture(parser.string(command)?.as_ref()); },
+            "describe" => { verex.describe(parser.string(command)?.as_ref()); },
+            "exactly_line" => { verex.exactly_line(parser.string(command)?.as_ref()); },
+            "find" | "then" => { verex.find(parser.string(command)?.as_ref()); },
+            "find_any_case" => { verex.find_any_case(parser.string(command)?.as_ref()); },
+            "maybe" => { verex.maybe(parser.string(command)?.as_ref()); },
+            "or_find" => { verex.or_find(parser.string(command)?.as_ref()); },
+            "something_but" => { verex.something_but(parser.string(command)?.as_ref()); },
+            "digits" => { verex.digits(parser.number(command)?); },
+            "repeat_n" | "repeat_previous" => { verex.repeat_n(parser.number(command)?); },
+            "digits_between" => {
+                let n = parser.number(command)?;
+                verex.digits_between(n, parser.number(command)?);
+            },
+            "repeat_n_to_m" => {
+                let n = parser.number(command)?;
+                verex.repeat_n_to_m(n, parser.number(command)?);
+            },
+            "ascii_digits" => { verex.ascii_digits(parser.boolean(command)?); },
+            "auto_capture" => { verex.auto_capture(parser.boolean(command)?); },
+            "multi_line" => { verex.multi_line(parser.boolean(command)?); },
+            "search_one_line" | "single_line" => { verex.single_line(parser.boolean(command)?); },
+            "tolerate_whitespace" => { verex.tolerate_whitespace(parser.boolean(command)?); },
+            "with_any_case" => { verex.with_any_case(parser.boolean(command)?); },
+            _ => return Err(DslError::new(dsl, start, DslErrorKind::UnknownCommand(command.to_owned()))),
+        }
+    }
+    Ok(verex)
+}
@@ -0,0 +1,76 @@
+use std::fmt;
+use std::sync::Arc;
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+
+/// A predicate matches have to pass in addition to the regex, e.g. a checksum
+#[derive(Clone)]
+#[cfg_attr(not(feature = "regex"), allow(dead_code))]
+pub(crate) struct Filter(Arc<dyn Fn(&str) -> bool + Send + Sync>);
+
+impl Filter {
+    pub(crate) fn new<F>(predicate: F) -> Filter where F: Fn(&str) -> bool + Send + Sync + 'static {
+        Filter(Arc::new(predicate))
+    }
+
+    #[cfg(feature = "regex")]
+    pub(crate) fn accepts(&self, matched: &str) -> bool {
+        (self.0)(matched)
+    }
+}
+
+impl fmt::Debug for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Filter")
+    }
+}
+
+/// Iterator over the start and end of the matches passing all filters, see `Verex::find_valid_iter()`
+#[cfg(feature = "regex")]
+#[derive(Debug)]
+pub struct FindValid<'t> {
+    regex: Regex,
+    filters: Vec<Filter>,
+    text: &'t str,
+    search: usize
+}
+
+#[cfg(feature = "regex")]
+impl<'t> FindValid<'t> {
+    /// Create the iterator from a compiled regex and the filters
+    pub(crate) fn new(regex: Regex, filters: Vec<Filter>, text: &'t str) -> FindValid<'t> {
+        FindValid {
+            regex,
+            filters,
+            text,
+            search: 0
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<'t> Iterator for FindValid<'t> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        while self.search <= self.text.len() {
+            let (start, end) = self.regex.find_at(self.text, self.search)?;
+            self.search = if start != end {
+                end
+            }
+            else {
+                // continue after the empty match without splitting a character
+                match self.text[start..].chars().next() {
+                    Some(c) => start + c.len_utf8(),
+                    None => self.text.len() + 1,
+                }
+            };
+            let matched = &self.text[start..end];
+            if self.filters.iter().all(|filter| filter.accepts(matched)) {
+                return Some((start, end));
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,51 @@
+use super::{Expression, Verex};
+
+/// A reusable piece of an expression that can be added to a `Verex` with `push()`
+///
+/// Implement this for domain types to make them composable with other fragments, e.g.
+/// `struct Port;` rendering `digits_between(1, 5)`.
+pub trait VerexFragment {
+    /// Add the tokens of this fragment to the end of the `Verex`
+    fn render_into(&self, verex: &mut Verex);
+}
+
+impl VerexFragment for Verex {
+    fn render_into(&self, verex: &mut Verex) {
+        verex.find_expr(Expression::Verex(self));
+    }
+}
+
+impl VerexFragment for str {
+    fn render_into(&self, verex: &mut Verex) {
+        verex.find(self);
+    }
+}
+
+impl VerexFragment for String {
+    fn render_into(&self, verex: &mut Verex) {
+        verex.find(self);
+    }
+}
+
+/// Boxed fragments, e.g. `Box<dyn VerexFragment>` to keep pieces of different types in one collection
+impl<F: VerexFragment + ?Sized> VerexFragment for Box<F> {
+    fn render_into(&self, verex: &mut Verex) {
+        (**self).render_into(verex);
+    }
+}
+
+/// A sequence of fragments, added one after another
+impl<F: VerexFragment> VerexFragment for [F] {
+    fn render_into(&self, verex: &mut Verex) {
+        for fragment in self {
+            fragment.render_into(verex);
+        }
+    }
+}
+
+/// A sequence of fragments, added one after another
+impl<F: VerexFragment> VerexFragment for Vec<F> {
+    fn render_into(&self, verex: &mut Verex) {
+        self[..].render_into(verex);
+    }
+}
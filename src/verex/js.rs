@@ -0,0 +1,211 @@
+use std::error;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+#[cfg(feature = "regex")]
+use regex::Error;
+
+use super::Verex;
+
+/// The errors that can occur when importing an expression of the JavaScript port
+#[derive(Debug)]
+pub enum JsImportError {
+    /// The input is neither a JSON object nor a regex literal like `/a/g`
+    InvalidInput(String),
+    /// The JSON object contains no pattern source
+    MissingSource,
+    /// A flag without an equivalent in this crate, e.g. `s` or `y`
+    UnsupportedFlag(char),
+    /// The translated pattern does not compile
+    #[cfg(feature = "regex")]
+    Regex(Error)
+}
+
+impl fmt::Display for JsImportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JsImportError::InvalidInput(ref reason) => write!(f, "invalid input: {}", reason),
+            JsImportError::MissingSource => write!(f, "no pattern source found"),
+            JsImportError::UnsupportedFlag(flag) => write!(f, "unsupported flag '{}'", flag),
+            #[cfg(feature = "regex")]
+            JsImportError::Regex(ref error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl error::Error for JsImportError {}
+
+fn invalid<T>(reason: &str) -> Result<T, JsImportError> {
+    Err(JsImportError::InvalidInput(reason.to_owned()))
+}
+
+/// Read a JSON string after its opening quote
+fn json_string(chars: &mut Peekable<Chars>) -> Result<String, JsImportError> {
+    let mut string = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(string),
+            Some('\\') => match chars.next() {
+                Some('b') => string.push('\u{8}'),
+                Some('f') => string.push('\u{C}'),
+                Some('n') => string.push('\n'),
+                Some('r') => string.push('\r'),
+                Some('t') => string.push('\t'),
+                Some('u') => {
+                    let mut unit = json_unicode_escape(chars)?;
+                    // combine surrogate pairs, looking ahead on a copy so nothing is consumed without a low surrogate
+                    if (0xD800..0xDC00).contains(&unit) {
+                        let mut rest = chars.clone();
+                        if rest.next() != Some('\\') || rest.next() != Some('u') {
+                            return invalid("unpaired surrogate in unicode escape");
+                        }
+                        let low = json_unicode_escape(&mut rest)?;
+                        if !(0xDC00..0xE000).contains(&low) {
+                            return invalid("unpaired surrogate in unicode escape");
+                        }
+                        *chars = rest;
+                        unit = 0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00);
+                    }
+                    match ::std::char::from_u32(unit) {
+                        Some(c) => string.push(c),
+                        None => return invalid("invalid unicode escape"),
+                    }
+                },
+                Some(c) => string.push(c),
+                None => return invalid("unterminated string"),
+            },
+            Some(c) => string.push(c),
+            None => return invalid("unterminated string"),
+        }
+    }
+}
+
+fn json_unicode_escape(chars: &mut Peekable<Chars>) -> Result<u32, JsImportError> {
+    let hex: String = chars.take(4).collect();
+    if hex.len() != 4 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return invalid("invalid unicode escape");
+    }
+    u32::from_str_radix(hex.as_ref(), 16).or_else(|_| invalid("invalid unicode escape"))
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.peek().map(|c| c.is_whitespace()) == Some(true) {
+        chars.next();
+    }
+}
+
+/// Read a flat JSON object, keeping only the members with string values
+fn json_object(json: &str) -> Result<Vec<(String, String)>, JsImportError> {
+    let mut chars = json.chars().peekable();
+    let mut members = Vec::new();
+    skip_whitespace(&mut chars);
+    if chars.next() != Some('{') {
+        return invalid("expected '{'");
+    }
+    loop {
+        skip_whitespace(&mut chars);
+        match chars.next() {
+            Some('}') => break,
+            Some('"') => {},
+            _ => return invalid("expected a member name"),
+        }
+        let key = json_string(&mut chars)?;
+        skip_whitespace(&mut chars);
+        if chars.next() != Some(':') {
+            return invalid("expected ':'");
+        }
+        skip_whitespace(&mut chars);
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            members.push((key, json_string(&mut chars)?));
+        }
+        else {
+            // skip numbers, booleans and null
+            while chars.peek().map(|&c| c != ',' && c != '}') == Some(true) {
+                chars.next();
+            }
+        }
+        skip_whitespace(&mut chars);
+        match chars.next() {
+            Some(',') => {},
+            Some('}') => break,
+            _ => return invalid("expected ',' or '}'"),
+        }
+    }
+    Ok(members)
+}
+
+/// Translate escapes of the JavaScript regex syntax the `regex` crate does not know
+fn translate_pattern(pattern: &str) -> String {
+    let mut result = String::new();
+    let mut in_class = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('-') if in_class => result.push_str(r"\x2D"),
+                Some(escaped) if escaped.is_ascii_punctuation() && !regex_syntax_punct(escaped) => result.push(escaped),
+                Some(escaped) => {
+                    result.push('\\');
+                    result.push(escaped);
+                },
+                None => result.push('\\'),
+            },
+            '[' if !in_class => {
+                in_class = true;
+                result.push(c);
+            },
+            ']' if in_class => {
+                in_class = false;
+                result.push(c);
+            },
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Whether the regex syntax allows escaping the punctuation character
+fn regex_syntax_punct(c: char) -> bool {
+    r"\.+*?()|[]{}^$#".contains(c)
+}
+
+/// Build a `Verex` from the pattern and flags of a JavaScript regex
+fn from_pattern(pattern: &str, flags: &str) -> Result<Verex, JsImportError> {
+    let mut verex = Verex::from_string(translate_pattern(pattern));
+    for flag in flags.chars() {
+        match flag {
+            'i' => { verex.with_any_case(true); },
+            'm' => { verex.multi_line(true); },
+            // global and unicode matching make no difference here
+            'g' | 'u' => {},
+            _ => return Err(JsImportError::UnsupportedFlag(flag)),
+        }
+    }
+    #[cfg(feature = "regex")]
+    verex.compile().map_err(JsImportError::Regex)?;
+    Ok(verex)
+}
+
+/// Import a JSON object with `source` and `flags` (or the `_prefixes`, `_source`, `_suffixes` and `_modifiers`
+/// of a JavaScript `VerEx`) or a regex literal like `/^(?:http)$/gm`
+pub fn import(input: &str) -> Result<Verex, JsImportError> {
+    let input = input.trim();
+    if input.starts_with('/') {
+        let end = match input.rfind('/') {
+            Some(end) if end > 0 => end,
+            _ => return invalid("unterminated regex literal"),
+        };
+        return from_pattern(&input[1..end], &input[end + 1..]);
+    }
+    let members = json_object(input)?;
+    let member = |name: &str| members.iter().find(|&(key, _)| key == name).map(|(_, value)| value.as_str());
+    let pattern = match (member("source"), member("_source")) {
+        (Some(source), _) => source.to_owned(),
+        (None, Some(source)) => format!("{}{}{}", member("_prefixes").unwrap_or(""), source, member("_suffixes").unwrap_or("")),
+        (None, None) => return Err(JsImportError::MissingSource),
+    };
+    let flags = member("flags").or_else(|| member("_modifiers")).unwrap_or("");
+    from_pattern(pattern.as_ref(), flags)
+}
@@ -0,0 +1,56 @@
+use regex::Regex;
+
+/// Iterator over the start and end of at most a given number of matches, see `Verex::find_iter_at_most()`
+///
+/// The text after the last returned match is never searched, so scanning a huge text stops early.
+#[derive(Debug)]
+pub struct FindAtMost<'t> {
+    regex: Regex,
+    text: &'t str,
+    search: usize,
+    remaining: usize
+}
+
+impl<'t> FindAtMost<'t> {
+    /// Create the iterator from a compiled regex and the maximum number of matches
+    pub(crate) fn new(regex: Regex, text: &'t str, limit: usize) -> FindAtMost<'t> {
+        FindAtMost {
+            regex,
+            text,
+            search: 0,
+            remaining: limit
+        }
+    }
+
+    /// Return the number of matches the iterator may still return
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<'t> Iterator for FindAtMost<'t> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        if self.remaining == 0 || self.search > self.text.len() {
+            return None;
+        }
+        let (start, end) = self.regex.find_at(self.text, self.search)?;
+        self.search = if start != end {
+            end
+        }
+        else {
+            // continue after the empty match without splitting a character
+            match self.text[start..].chars().next() {
+                Some(c) => start + c.len_utf8(),
+                None => self.text.len() + 1,
+            }
+        };
+        self.remaining -= 1;
+        Some((start, end))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
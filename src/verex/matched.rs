@@ -0,0 +1,114 @@
+use std::ops::Range;
+
+use regex::{Captures, Regex};
+
+/// The name of a capturing group and its byte offsets if it took part in the match
+type Group = (Option<String>, Option<(usize, usize)>);
+
+/// A match of a `Verex` in a text together with its capturing groups
+///
+/// Unlike `regex::Captures` it keeps the whole text, so the surrounding context can be extracted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerexMatch<'t> {
+    text: &'t str,
+    start: usize,
+    end: usize,
+    groups: Vec<Group>
+}
+
+impl<'t> VerexMatch<'t> {
+    /// Create the match from the captures of a compiled regex
+    pub(crate) fn new(regex: &Regex, text: &'t str, captures: &Captures) -> VerexMatch<'t> {
+        let (start, end) = captures.pos(0).unwrap_or((0, 0));
+        let groups = regex.capture_names().enumerate().skip(1)
+                          .map(|(index, name)| (name.map(|name| name.to_owned()), captures.pos(index)))
+                          .collect();
+        VerexMatch { text, start, end, groups }
+    }
+
+    /// Return the matched text
+    pub fn as_str(&self) -> &'t str {
+        &self.text[self.start..self.end]
+    }
+
+    /// Return the byte offset of the start of the match
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Return the byte offset of the end of the match
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Return the byte range of the match
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Return the text of the capturing group with the given index (0 is the whole match),
+    /// or `None` if the group did not take part in the match
+    pub fn group(&self, index: usize) -> Option<&'t str> {
+        if index == 0 {
+            return Some(self.as_str());
+        }
+        self.groups.get(index - 1).and_then(|&(_, pos)| pos).map(|(start, end)| &self.text[start..end])
+    }
+
+    /// Return the text of the named capturing group, or `None` if the group did not take part in the match
+    pub fn name(&self, name: &str) -> Option<&'t str> {
+        self.groups.iter()
+            .find(|group| group.0.as_ref().map(|group_name| group_name == name) == Some(true))
+            .and_then(|&(_, pos)| pos)
+            .map(|(start, end)| &self.text[start..end])
+    }
+
+    /// Return the names and texts of the named groups that took part in the match
+    pub fn named_groups(&self) -> Vec<(&str, &'t str)> {
+        self.groups.iter()
+            .filter_map(|group| match *group {
+                (Some(ref name), Some((start, end))) => Some((name.as_str(), &self.text[start..end])),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Return the match together with up to `n` characters before and after it
+    pub fn context(&self, n: usize) -> &'t str {
+        let from = self.text[..self.start].char_indices().rev().take(n).last().map(|(i, _)| i).unwrap_or(self.start);
+        let to = self.text[self.end..].char_indices().nth(n).map(|(i, _)| self.end + i).unwrap_or(self.text.len());
+        &self.text[from..to]
+    }
+
+    /// Return the lines the match is on together with up to `n` lines before and after them,
+    /// without the final line break
+    pub fn context_lines(&self, n: usize) -> &'t str {
+        let mut from = line_start(self.text, self.start);
+        for _ in 0..n {
+            if from == 0 {
+                break;
+            }
+            from = line_start(self.text, from - 1);
+        }
+        // a match ending with a line break ends on the line of the break
+        let last = if self.end > self.start && self.text[..self.end].ends_with('\n') { self.end - 1 } else { self.end };
+        let mut to = line_end(self.text, last);
+        for _ in 0..n {
+            if to == self.text.len() {
+                break;
+            }
+            to = line_end(self.text, to + 1);
+        }
+        &self.text[from..to]
+    }
+}
+
+/// Return the offset of the start of the line containing the offset
+fn line_start(text: &str, offset: usize) -> usize {
+    text[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0)
+}
+
+/// Return the offset of the line break ending the line containing the offset, or the end of the text
+fn line_end(text: &str, offset: usize) -> usize {
+    text[offset..].find('\n').map(|i| offset + i).unwrap_or(text.len())
+}
@@ -1,19 +1,142 @@
+#[cfg(feature = "regex")]
 pub use regex::Regex;
-use regex::Error;
-
-#[cfg(test)] pub mod test;
+#[cfg(feature = "regex")]
+use regex::{Captures, Error, RegexBuilder};
+use std::borrow::Cow;
+#[cfg(feature = "regex")]
+use std::mem;
+use std::ops::{Bound, RangeBounds};
+use std::panic::Location;
+#[cfg(feature = "regex")]
+use std::sync::Arc;
+use std::sync::OnceLock;
+#[cfg(all(feature = "rayon", feature = "regex"))]
+use rayon::prelude::*;
+#[cfg(feature = "regex-automata")]
+use regex_automata::dfa::{dense, sparse};
+
+#[cfg(feature = "regex-automata")]
+pub use self::analysis::AnalysisError;
+#[cfg(feature = "regex-automata")]
+pub use self::bundle::{BundleError, PatternBundle};
+pub use self::captures::CaptureStep;
+pub use self::charset::CharSet;
+pub use self::config::{CaseFolding, CompileOptions, EscapeMode, JoinPolicy, LineBreak, VerexConfig};
+pub use self::dialect::ExportError;
+pub use self::diff::{PartDiff, VerexDiff};
+pub use self::dsl::{DslError, DslErrorKind};
+#[cfg(feature = "regex")]
+pub use self::filters::FindValid;
+pub use self::fragment::VerexFragment;
+pub use self::js::JsImportError;
+pub use self::ranges::{RangeError, RangeOptions};
+#[cfg(feature = "regex")]
+pub use self::limited::FindAtMost;
+#[cfg(feature = "regex")]
+pub use self::matched::VerexMatch;
+#[cfg(feature = "regex")]
+pub use self::pipeline::{Pipeline, PipelineOutput};
+#[cfg(feature = "regex")]
+pub use self::registry::{PatternRegistry, RegistryError, VerexProvider};
+#[cfg(feature = "regex")]
+pub use self::router::{RouteMatch, RoutePolicy, VerexRouter};
+#[cfg(feature = "regex")]
+pub use self::segments::{Segment, Segments};
+#[cfg(feature = "regex")]
+pub use self::shared_cache::{clear_shared_cache, shared_cache_len};
+pub use self::shell::Shell;
+#[cfg(feature = "regex")]
+pub use self::tokenizer::{Token, TokenizeError, Tokenizer, Tokens};
+#[cfg(feature = "regex")]
+pub use self::validator::{Validator, Violation};
+#[cfg(all(feature = "clap", feature = "regex"))]
+pub use self::value_parser::VerexValueParser;
+use self::captures::{count_capture_groups, map_capture_groups, strip_capture_groups};
+use self::dialect::Dialect;
+use self::filters::Filter;
+
+#[cfg(feature = "regex-automata")]
+mod analysis;
+#[cfg(feature = "regex-automata")]
+mod bundle;
+mod captures;
+mod charset;
+mod config;
+mod dialect;
+mod diff;
+mod dsl;
+mod filters;
+mod fragment;
+mod js;
+#[cfg(feature = "regex")]
+mod limited;
+#[cfg(feature = "regex")]
+mod matched;
+#[cfg(feature = "regex")]
+mod registry;
+#[cfg(feature = "regex")]
+mod router;
+mod normalize;
+mod ranges;
+#[cfg(feature = "regex")]
+mod pipeline;
+#[cfg(feature = "regex")]
+mod segments;
+#[cfg(feature = "regex")]
+mod shared_cache;
+mod shell;
+#[cfg(feature = "regex")]
+mod tokenizer;
+#[cfg(feature = "regex")]
+mod validator;
+#[cfg(all(feature = "clap", feature = "regex"))]
+mod value_parser;
+#[cfg(all(test, feature = "regex"))] pub mod test;
 
 bitflags! {
-    flags Modifiers: u8 {
-        const MULTI_LINE        = 0b00000001,
-        const CASE_INSENSITIVE  = 0b00000010,
+    /// The modifiers of a `Verex`, e.g. to persist and restore them with `modifiers()` and `set_modifiers()`
+    pub struct ModifierSet: u8 {
+        /// ^ and $ match line start and end
+        const MULTI_LINE        = 0b00000001;
+        /// Letters match regardless of their case
+        const CASE_INSENSITIVE  = 0b00000010;
+        /// `digit()` only matches the ASCII digits 0-9
+        const ASCII_DIGITS      = 0b00000100;
+        /// `word_char()`, `word()` and `whole_word()` only match ASCII letters, digits and `_`
+        const ASCII_WORDS       = 0b00001000;
     }
 }
 
-/// The struct used for keeping track of the modifiers
-impl Modifiers {
-    pub fn new() -> Modifiers {
-        Modifiers::empty()
+impl ModifierSet {
+    /// Create an empty set, the modifiers of a new `Verex`
+    pub const fn new() -> ModifierSet {
+        ModifierSet::empty()
+    }
+
+    /// Whether ^ and $ match line start and end
+    pub fn is_multi_line(& self) -> bool {
+        self.contains(ModifierSet::MULTI_LINE)
+    }
+
+    /// Whether letters match regardless of their case
+    pub fn is_case_insensitive(& self) -> bool {
+        self.contains(ModifierSet::CASE_INSENSITIVE)
+    }
+
+    /// Whether `digit()` only matches the ASCII digits 0-9
+    pub fn is_ascii_digits(& self) -> bool {
+        self.contains(ModifierSet::ASCII_DIGITS)
+    }
+
+    /// Whether the word tokens only match ASCII characters
+    pub fn is_ascii_words(& self) -> bool {
+        self.contains(ModifierSet::ASCII_WORDS)
+    }
+}
+
+impl Default for ModifierSet {
+    fn default() -> ModifierSet {
+        ModifierSet::new()
     }
 }
 
@@ -24,105 +147,574 @@ pub enum Expression<'e> {
     /// The variant for handing over a Verex reference
     Verex(&'e Verex),
     /// The variant for handing over a Regex reference
+    #[cfg(feature = "regex")]
     Regex(&'e Regex)
 }
 
+/// Standard tokens, so methods taking an `Expression` and the `or_expr!` macro can be fed them without regex strings
+impl Expression<'static> {
+    /// A digit of any script (`\d`)
+    pub const DIGIT: Expression<'static> = Expression::String(r"\d");
+    /// Any character except a digit (`\D`)
+    pub const NON_DIGIT: Expression<'static> = Expression::String(r"\D");
+    /// A letter, digit or `_` of any script (`\w`)
+    pub const WORD_CHAR: Expression<'static> = Expression::String(r"\w");
+    /// Any character except a word character (`\W`)
+    pub const NON_WORD_CHAR: Expression<'static> = Expression::String(r"\W");
+    /// A whitespace character (`\s`)
+    pub const WHITESPACE: Expression<'static> = Expression::String(r"\s");
+    /// Any character except whitespace (`\S`)
+    pub const NON_WHITESPACE: Expression<'static> = Expression::String(r"\S");
+    /// Any character including line breaks
+    pub const ANY_CHAR: Expression<'static> = Expression::String(r"(?s:.)");
+    /// A single space
+    pub const SPACE: Expression<'static> = Expression::String(r" ");
+    /// A tab (`\t`)
+    pub const TAB: Expression<'static> = Expression::String(r"\t");
+    /// A newline (`\n`)
+    pub const NEWLINE: Expression<'static> = Expression::String(r"\n");
+    /// A carriage return (`\r`)
+    pub const CARRIAGE_RETURN: Expression<'static> = Expression::String(r"\r");
+    /// A line break, `\n` or `\r\n`
+    pub const LINE_BREAK: Expression<'static> = Expression::String(r"(?:\n|\r\n)");
+    /// The boundary between a word character and another character (`\b`)
+    pub const WORD_BOUNDARY: Expression<'static> = Expression::String(r"\b");
+    /// Any position that is not a word boundary (`\B`)
+    pub const NOT_WORD_BOUNDARY: Expression<'static> = Expression::String(r"\B");
+    /// The start of the text, or of a line with `multi_line()` (`^`)
+    pub const START_OF_LINE: Expression<'static> = Expression::String(r"^");
+    /// The end of the text, or of a line with `multi_line()` (`$`)
+    pub const END_OF_LINE: Expression<'static> = Expression::String(r"$");
+    /// The start of the text regardless of `multi_line()` (`\A`)
+    pub const START_OF_TEXT: Expression<'static> = Expression::String(r"\A");
+    /// The end of the text regardless of `multi_line()` (`\z`)
+    pub const END_OF_TEXT: Expression<'static> = Expression::String(r"\z");
+}
+
 macro_rules! match_expr {
     ( $e:expr, $this:expr, $method:ident ) => {
         {
             match $e {
                 Expression::String(x) => { $this.$method(x) },
                 Expression::Verex(x) => { $this.$method(x.source()) },
+                #[cfg(feature = "regex")]
                 Expression::Regex(x) => { $this.$method(x.as_str()) },
             }
         }
+    };
+    ( $e:expr, $this:expr, $method:ident, $( $arg:expr ),+ ) => {
+        {
+            match $e {
+                Expression::String(x) => { $this.$method(x, $( $arg ),+) },
+                Expression::Verex(x) => { $this.$method(x.source(), $( $arg ),+) },
+                #[cfg(feature = "regex")]
+                Expression::Regex(x) => { $this.$method(x.as_str(), $( $arg ),+) },
+            }
+        }
+    }
+}
+
+/// Return the minimum and maximum number of repetitions a range allows
+fn repetition_bounds<R: RangeBounds<u32>>(bounds: R) -> (u32, Option<u32>) {
+    let n = match bounds.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n + 1,
+        Bound::Unbounded => 0,
+    };
+    let m = match bounds.end_bound() {
+        Bound::Included(&m) => Some(m),
+        Bound::Excluded(&m) => Some(m.saturating_sub(1)),
+        Bound::Unbounded => None,
+    };
+    (n, m)
+}
+
+/// Return the bounds of a quantifier without the braces, e.g. `2,4`
+fn quantifier_bounds(n: u32, m: Option<u32>) -> String {
+    match m {
+        Some(m) if m == n => n.to_string(),
+        Some(m) => format!("{},{}", n, m),
+        None => format!("{},", n),
+    }
+}
+
+/// The characters with a special meaning in the regex syntax
+const SPECIAL_CHARS: &str = r"\()[]{}.+*?^$|";
+
+/// Escape all characters with a special meaning in the regex syntax
+///
+/// The string is only copied if it contains such characters.
+pub fn escape(string: &str) -> Cow<'_, str> {
+    if !string.contains(|c| SPECIAL_CHARS.contains(c)) {
+        return Cow::Borrowed(string);
+    }
+    let mut result = String::with_capacity(string.len() * 2);
+    for c in string.chars() {
+        if SPECIAL_CHARS.contains(c) {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    Cow::Owned(result)
+}
+
+/// Escape a character for use inside a character class
+pub(crate) fn escape_class_char(c: char) -> String {
+    match c {
+        '-' => r"\x2D".to_owned(),
+        _ => escape(c.to_string().as_ref()).into_owned(),
+    }
+}
+
+/// Expand the letters of a literal into character classes of both cases and escape the rest
+pub(crate) fn expand_any_case(string: &str) -> String {
+    let mut result = String::new();
+    let mut literal = String::new();
+    for c in string.chars() {
+        let lower: Vec<char> = c.to_lowercase().collect();
+        let upper: Vec<char> = c.to_uppercase().collect();
+        if lower.len() == 1 && upper.len() == 1 && lower[0] != upper[0] {
+            result.push_str(escape(literal.as_ref()).as_ref());
+            literal.clear();
+            result.push('[');
+            result.push(lower[0]);
+            result.push(upper[0]);
+            if c != lower[0] && c != upper[0] {
+                result.push(c);
+            }
+            result.push(']');
+        }
+        else {
+            literal.push(c);
+        }
+    }
+    result.push_str(escape(literal.as_ref()).as_ref());
+    result
+}
+
+/// Whether the regex string is empty or a single item like a group, class or (escaped) character
+fn is_single_item(string: &str) -> bool {
+    let chars: Vec<char> = string.chars().collect();
+    let end = match chars.first() {
+        None => return true,
+        Some('(') => {
+            let mut depth = 0;
+            let mut in_class = false;
+            let mut i = 0;
+            while i < chars.len() {
+                match chars[i] {
+                    '\\' => i += 1,
+                    '[' if !in_class => in_class = true,
+                    ']' if in_class => in_class = false,
+                    '(' if !in_class => depth += 1,
+                    ')' if !in_class => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    },
+                    _ => {},
+                }
+                i += 1;
+            }
+            i + 1
+        },
+        Some('[') => {
+            let mut i = 1;
+            if chars.get(i) == Some(&'^') {
+                i += 1;
+            }
+            // a closing bracket right at the start is a literal
+            if chars.get(i) == Some(&']') {
+                i += 1;
+            }
+            while i < chars.len() && chars[i] != ']' {
+                match chars[i] {
+                    '\\' => i += 1,
+                    // skip ASCII classes like [:alpha:]
+                    '[' if chars.get(i + 1) == Some(&':') => {
+                        while i + 1 < chars.len() && !(chars[i] == ':' && chars[i + 1] == ']') {
+                            i += 1;
+                        }
+                        i += 1;
+                    },
+                    _ => {},
+                }
+                i += 1;
+            }
+            i + 1
+        },
+        Some('\\') => match chars.get(1) {
+            Some('p') | Some('P') | Some('x') if chars.get(2) == Some(&'{') => {
+                chars.iter().position(|&c| c == '}').map(|i| i + 1).unwrap_or(chars.len())
+            },
+            Some('x') => 4,
+            _ => 2,
+        },
+        Some(_) => 1,
+    };
+    end >= chars.len()
+}
+
+/// Render the class of the characters matched by an expression for the `_expr` variants of
+/// `any()`, `anything_but()` and `something_but()`
+#[track_caller]
+fn expr_class(value: &str, negated: bool) -> String {
+    match normalize::char_class(value, negated) {
+        Some(class) => class,
+        None => panic!("`{}` matches more than single characters", value),
     }
 }
 
-const ESCAPE_PAIRS: [(&'static str, &'static str); 14] = [
-    (r"(?P<back_slash>\\)", "\\$back_slash"),
-    (r"(?P<open_group>\()", "\\$open_group"),
-    (r"(?P<close_group>\))", "\\$close_group"),
-    (r"(?P<open_class>\[)", "\\$open_class"),
-    (r"(?P<close_class>\])", "\\$close_class"),
-    (r"(?P<open_quantifier>\{)", "\\$open_quantifier"),
-    (r"(?P<close_quantifier>\})", "\\$close_quantifier"),
-    (r"(?P<dot>\.)", "\\$dot"),
-    (r"(?P<plus>\+)", "\\$plus"),
-    (r"(?P<star>\*)", "\\$star"),
-    (r"(?P<question_mark>\?)", "\\$question_mark"),
-    (r"(?P<circumflex>\^)", "\\$circumflex"),
-    (r"(?P<dollar>\$)", "\\$dollar"),
-    (r"(?P<pipe>\|)", "\\$pipe"),
-];
-
-fn escape(string: &str) -> String {
-    let mut result = string.to_owned();
-    for pair in ESCAPE_PAIRS.into_iter() {
-        let regex = Regex::new(pair.0).unwrap();
-        result = regex.replace_all(result.as_ref(), pair.1);
+/// Hash bytes with 64 bit FNV-1a, which unlike the hashers of std is guaranteed to stay the same
+fn fnv1a<I: Iterator<Item = u8>>(bytes: I) -> u64 {
+    bytes.fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// Uppercase the first letter of every word and lowercase the others
+#[cfg(feature = "regex")]
+fn titlecase(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut word_start = true;
+    for c in text.chars() {
+        if word_start {
+            result.extend(c.to_uppercase());
+        }
+        else {
+            result.extend(c.to_lowercase());
+        }
+        word_start = !c.is_alphanumeric();
     }
     result
 }
 
+/// A logical part of the builder string as added by one builder method
+#[derive(Debug, Clone)]
+struct Part {
+    start: usize,
+    note: Option<String>,
+    // only reported by `explain_error()`
+    #[cfg_attr(not(feature = "regex"), allow(dead_code))]
+    location: Option<&'static Location<'static>>
+}
+
+/// The cache of the compiled regex, shared by a `Verex` and its clones
+///
+/// The shared cell is only allocated when it is first used, so an empty cache can be created in a `const fn`.
+#[cfg(feature = "regex")]
+#[derive(Debug)]
+struct CompileCache(OnceLock<Arc<OnceLock<Regex>>>);
+
+#[cfg(feature = "regex")]
+impl CompileCache {
+    const fn new() -> CompileCache {
+        CompileCache(OnceLock::new())
+    }
+
+    fn shared(& self) -> &Arc<OnceLock<Regex>> {
+        self.0.get_or_init(Arc::default)
+    }
+
+    fn get(& self) -> Option<&Regex> {
+        self.0.get().and_then(|shared| shared.get())
+    }
+
+    fn set(& self, regex: Regex) {
+        let _ = self.shared().set(regex);
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Clone for CompileCache {
+    fn clone(&self) -> CompileCache {
+        CompileCache(OnceLock::from(self.shared().clone()))
+    }
+}
+
 /// The struct used for building verbal expression objects
 #[derive(Debug, Clone)]
 pub struct Verex {
     string: String,
-    modifiers: Modifiers,
-    source: String
+    modifiers: ModifierSet,
+    source: OnceLock<String>,
+    #[cfg(feature = "regex")]
+    compiled: CompileCache,
+    separator: Option<String>,
+    after_token: bool,
+    parts: Vec<Part>,
+    auto_capture: bool,
+    capture_wildcards: bool,
+    escape_values: bool,
+    group_before_quantifiers: bool,
+    line_break: LineBreak,
+    capture_steps: Vec<CaptureStep>,
+    repeated_captures: Vec<(String, String)>,
+    branches: Vec<String>,
+    // number of parts and length of the string right after the last branch, while nothing followed it
+    branch_group_end: Option<(usize, usize)>,
+    longest_match: bool,
+    track_locations: bool,
+    case_folding: CaseFolding,
+    filters: Vec<Filter>,
+    #[cfg(feature = "fancy-regex")]
+    backtrack_limit: Option<usize>
 }
 
 impl Verex {
     // constructors
     /// Standard Constructor
-    pub fn new() -> Verex {
-        Verex::from_string(String::new())
+    ///
+    /// This is a `const fn`, so an empty `Verex` can be put in a `static` and cloned to build on it.
+    pub const fn new() -> Verex {
+        Verex {
+            string: String::new(),
+            modifiers: ModifierSet::new(),
+            source: OnceLock::new(),
+            #[cfg(feature = "regex")]
+            compiled: CompileCache::new(),
+            separator: None,
+            after_token: false,
+            parts: Vec::new(),
+            auto_capture: false,
+            capture_wildcards: true,
+            escape_values: true,
+            group_before_quantifiers: true,
+            line_break: LineBreak::Any,
+            capture_steps: Vec::new(),
+            repeated_captures: Vec::new(),
+            branches: Vec::new(),
+            branch_group_end: None,
+            longest_match: false,
+            track_locations: false,
+            case_folding: CaseFolding::Simple,
+            filters: Vec::new(),
+            #[cfg(feature = "fancy-regex")]
+            backtrack_limit: None
+        }
+    }
+
+    /// Create an empty `Verex` whose builder string can hold `capacity` bytes without reallocating,
+    /// e.g. when generating many patterns of a known size
+    pub fn with_capacity(capacity: usize) -> Verex {
+        Verex::from_string(String::with_capacity(capacity))
     }
 
     /// Create a `Verex` object from a `String`
     pub fn from_string(string: String) -> Verex {
+        let after_token = !string.is_empty();
+        let parts = if string.is_empty() {
+            Vec::new()
+        }
+        else {
+            vec![Part { start: 0, note: None, location: None }]
+        };
         let mut verex = Verex {
-            string: string,
-            modifiers: Modifiers::new(),
-            source: String::new()
+            string,
+            after_token,
+            parts,
+            ..Verex::new()
         };
         verex.update_source_with_modifiers();
         verex
     }
 
     /// Create a `Verex` object from a `&str`
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(string: &str) -> Verex {
         Verex::from_string(string.to_owned())
     }
 
+    /// Create a `Verex` object from the expressions in order, either as sequence or as alternatives
+    #[track_caller]
+    pub fn from_parts(parts: Vec<Expression>, join: JoinPolicy) -> Verex {
+        let mut verex = Verex::new();
+        for (i, expr) in parts.into_iter().enumerate() {
+            if i > 0 && join == JoinPolicy::Alternation {
+                verex.or();
+            }
+            verex.find_expr(expr);
+        }
+        verex
+    }
+
+    /// Create a `Verex` object from an expression of the JavaScript port, given as JSON object
+    /// with `source` and `flags` (or the `_prefixes`, `_source`, `_suffixes` and `_modifiers` of a `VerEx`)
+    /// or as regex literal like `/^(?:http)(?:s)?$/gm`
+    ///
+    /// The `i` and `m` flags are applied, `g` and `u` are ignored and other flags are rejected.
+    pub fn from_js_verbal_expression(json: &str) -> Result<Verex, JsImportError> {
+        js::import(json)
+    }
+
+    /// Create an empty `Verex` object with the settings of the config
+    pub fn with_config(config: &VerexConfig) -> Verex {
+        let mut verex = Verex::new();
+        verex.group_before_quantifiers = config.group_before_quantifiers;
+        verex.capture_wildcards = config.capture_wildcards;
+        verex.auto_capture = config.auto_capture;
+        verex.escape_values = config.escape_values;
+        verex.line_break = config.line_break;
+        verex.ascii_digits(!config.unicode)
+             .ascii_words(!config.unicode);
+        verex
+    }
+
+    /// Create a `Verex` object from builder method names and their arguments,
+    /// e.g. `start_of_line find 'http' maybe 's' end_of_line`
+    ///
+    /// Strings are quoted with `'` or `"`, numbers and `true`/`false` are written as is.
+    pub fn parse_dsl(dsl: &str) -> Result<Verex, DslError> {
+        dsl::parse(dsl)
+    }
+
     // --------------------------------------------------
     // fundamental methods
+    /// Reserve room for a value wrapped in a group with a quantifier, so it is added with at most one reallocation
+    fn reserve_group(&mut self, value: &str) -> &mut Verex {
+        self.string.reserve(value.len() + r"(?:)?".len());
+        self
+    }
+
     /// Add a string to the regex string in the `Verex` and return self
     fn add(&mut self, value: &str) -> &mut Verex {
         self.string.push_str(value);
         self
     }
 
-    /// Update the source string from the (presumably changed) builder string
+    /// Invalidate the source string and the compiled regex after the builder string or the modifiers changed
     fn update_source_with_modifiers(&mut self) -> &mut Verex {
-        self.source.clear();
-        self.source.push_str(r"(?");
-        if self.modifiers.contains(CASE_INSENSITIVE) {
-            self.source.push('i');
-        }
-        if self.modifiers.contains(MULTI_LINE) {
-            self.source.push('m');
+        // the source is only rendered when it is read, so long chains of builder calls stay linear;
+        // clones made before this change keep the caches for their source
+        self.source = OnceLock::new();
+        #[cfg(feature = "regex")]
+        {
+            self.compiled = CompileCache::new();
         }
-        self.source.push(':');
-        self.source.push_str(self.string.as_ref());
-        self.source.push(')');
+        #[cfg(feature = "tracing")]
+        debug!(string = %self.string, "updated verex");
         self
     }
 
     /// Compile the `Verex` to a `Regex` and return the result
+    ///
+    /// The compiled `Regex` is cached and shared with all clones of the `Verex` until they are changed.
+    #[cfg(feature = "regex")]
     pub fn compile(& self) -> Result<Regex, Error> {
-        Regex::new(self.source.as_ref())
+        if let Some(regex) = self.compiled.get() {
+            return Ok(regex.clone());
+        }
+        let result = Regex::new(self.source());
+        #[cfg(all(feature = "tracing", feature = "regex"))]
+        match result {
+            Ok(_) => debug!(source = %self.source(), "compiled verex"),
+            Err(ref error) => debug!(source = %self.source(), error = %error, "failed to compile verex"),
+        }
+        if let Ok(ref regex) = result {
+            self.compiled.set(regex.clone());
+        }
+        result
+    }
+
+    /// Compile the `Verex` to a `Regex` using a cache of the current thread keyed by the source
+    ///
+    /// Unlike `compile()` the regex is also shared with `Verex` values that are built anew with the
+    /// same source, e.g. for rules evaluated per request. The cache holds at most 256 regexes.
+    #[cfg(feature = "regex")]
+    pub fn compile_shared(& self) -> Result<Regex, Error> {
+        if let Some(regex) = self.compiled.get() {
+            return Ok(regex.clone());
+        }
+        let regex = shared_cache::get_or_compile(self.source())?;
+        self.compiled.set(regex.clone());
+        Ok(regex)
+    }
+
+    /// Compile the `Verex` to a `regex::bytes::Regex` that matches arbitrary bytes instead of UTF-8 text
+    ///
+    /// This is needed for expressions built with `byte()` and `byte_range()` that match bytes above `0x7F`.
+    #[cfg(feature = "regex")]
+    pub fn compile_bytes(& self) -> Result<regex::bytes::Regex, Error> {
+        regex::bytes::Regex::new(self.source())
+    }
+
+    /// Compile the `Verex` with all capturing groups turned into non-capturing ones
+    ///
+    /// The resulting `Regex` is faster when only `is_match()` or `find()` is needed.
+    #[cfg(feature = "regex")]
+    pub fn compile_for_match(& self) -> Result<Regex, Error> {
+        Regex::new(strip_capture_groups(self.source()).as_ref())
+    }
+
+    /// Compile the `Verex` to a `Regex` with other size limits than `compile()`, e.g. for an expression
+    /// that is too big for the default limits
+    ///
+    /// The result is not cached.
+    #[cfg(feature = "regex")]
+    pub fn compile_with(& self, options: CompileOptions) -> Result<Regex, Error> {
+        RegexBuilder::new(self.source())
+            .size_limit(options.size_limit)
+            .dfa_size_limit(options.dfa_size_limit)
+            .compile()
+    }
+
+    /// Compile the `Verex` to a dense DFA that can be serialized with `to_bytes_little_endian()` etc.
+    /// and loaded again without compiling (Unicode word boundaries are not supported)
+    #[cfg(feature = "regex-automata")]
+    #[allow(clippy::result_large_err)]
+    pub fn compile_dense_dfa(& self) -> Result<dense::DFA<Vec<u32>>, dense::BuildError> {
+        dense::DFA::new(self.source())
+    }
+
+    /// Compile the `Verex` to a sparse DFA, which is smaller but slower than a dense one
+    #[cfg(feature = "regex-automata")]
+    #[allow(clippy::result_large_err)]
+    pub fn compile_sparse_dfa(& self) -> Result<sparse::DFA<Vec<u8>>, dense::BuildError> {
+        sparse::DFA::new(self.source())
+    }
+
+    /// Compile the `Verex` with the backtracking `fancy_regex` engine
+    ///
+    /// This is needed for constructs the `regex` crate does not support, e.g. atomic groups.
+    #[cfg(feature = "fancy-regex")]
+    pub fn compile_fancy(& self) -> Result<fancy_regex::Regex, fancy_regex::Error> {
+        let mut builder = fancy_regex::RegexBuilder::new(self.source());
+        if let Some(limit) = self.backtrack_limit {
+            builder.backtrack_limit(limit);
+        }
+        let result = builder.build();
+        #[cfg(feature = "tracing")]
+        match result {
+            Ok(_) => debug!(source = %self.source(), "compiled verex with fancy-regex"),
+            Err(ref error) => debug!(source = %self.source(), error = %error, "failed to compile verex with fancy-regex"),
+        }
+        result
+    }
+
+    /// Create an empty `Verex` sharing the configuration, for building sub-expressions
+    ///
+    /// The capture steps are carried over so generated group names continue, see `merge_nested()`.
+    fn nested(& self) -> Verex {
+        let mut verex = Verex::new();
+        verex.modifiers = self.modifiers;
+        verex.auto_capture = self.auto_capture;
+        verex.capture_steps = self.capture_steps.clone();
+        verex.separator = self.separator.clone();
+        verex.capture_wildcards = self.capture_wildcards;
+        verex.escape_values = self.escape_values;
+        verex.group_before_quantifiers = self.group_before_quantifiers;
+        verex.line_break = self.line_break;
+        verex
+    }
+
+    /// Take over the capture steps added to a sub-expression from `nested()`, whose string is added
+    /// after `offset` capturing groups
+    fn merge_nested(&mut self, inner: Verex, offset: usize) {
+        let known = self.capture_steps.len();
+        self.capture_steps.extend(inner.capture_steps.into_iter().skip(known).map(|step| CaptureStep {
+            index: step.index + offset,
+            ..step
+        }));
+        self.repeated_captures.extend(inner.repeated_captures);
     }
 
     /// Return the raw regex string contained in the `Verex`
@@ -131,13 +723,76 @@ impl Verex {
     }
 
     /// Compile the `Verex` to a `Regex` and return the result
+    #[cfg(feature = "regex")]
     pub fn regex(& self) -> Result<Regex, Error> {
         self.compile()
     }
 
     /// Return the raw regex string contained in the `Verex`
     pub fn source(& self) -> &str {
-        self.source.as_ref()
+        self.source.get_or_init(|| {
+            let reordered = if self.longest_match { normalize::longest_first(self.string.as_ref()) } else { None };
+            let string = reordered.as_ref().unwrap_or(&self.string);
+            let folded = if self.modifiers.contains(ModifierSet::CASE_INSENSITIVE) && self.case_folding != CaseFolding::Simple {
+                normalize::fold_case(string, self.case_folding).ok()
+            }
+            else {
+                None
+            };
+            let (string, mut source) = match folded {
+                // the letters are expanded, so the `i` flag is left out
+                Some(ref folded) => (folded, self.flags_prefix().replace('i', "")),
+                None => (string, self.flags_prefix()),
+            };
+            source.reserve(string.len() + 1);
+            source.push_str(string.as_ref());
+            source.push(')');
+            source
+        })
+    }
+
+    /// Render the expression as basic regular expression for `grep` and `sed` (with GNU extensions)
+    ///
+    /// Non-capturing groups become capturing groups and case-insensitive letters become bracket expressions.
+    pub fn source_bre(& self) -> Result<String, ExportError> {
+        dialect::render(self.source(), Dialect::GnuBre)
+    }
+
+    /// Render the expression as extended regular expression for `grep -E` and `sed -E` (with GNU extensions)
+    ///
+    /// Unlike `to_posix_ere()` this supports word boundaries.
+    pub fn source_ere(& self) -> Result<String, ExportError> {
+        dialect::render(self.source(), Dialect::GnuEre)
+    }
+
+    /// Return the regex string in verbose mode with one part per line and the notes as comments
+    pub fn source_verbose(& self) -> String {
+        let mut result = self.flags_prefix();
+        result.insert(2, 'x');
+        result.push('\n');
+        for (string, note) in self.part_strs() {
+            result.push_str("    ");
+            let mut escaped = false;
+            for c in string.chars() {
+                if c.is_whitespace() {
+                    result.push_str(format!(r"\x{{{:X}}}", c as u32).as_ref());
+                }
+                else if c == '#' && !escaped {
+                    result.push_str(r"\#");
+                }
+                else {
+                    result.push(c);
+                }
+                escaped = c == '\\' && !escaped;
+            }
+            if let Some(note) = note {
+                result.push_str("  # ");
+                result.push_str(note.replace('\n', " ").as_ref());
+            }
+            result.push('\n');
+        }
+        result.push(')');
+        result
     }
 
     /// Return the raw regex string contained in the `Verex`
@@ -145,6 +800,112 @@ impl Verex {
         self.source()
     }
 
+    /// Create a clap value parser that only accepts arguments matching the expression
+    ///
+    /// Rejected values are reported together with the regex source or, if notes were added with
+    /// `describe()`, the `explain()` output.
+    #[cfg(all(feature = "clap", feature = "regex"))]
+    pub fn value_parser(& self) -> Result<VerexValueParser, Error> {
+        let description = if self.parts.iter().any(|part| part.note.is_some()) {
+            format!("\n{}", self.explain())
+        }
+        else {
+            format!("`{}`", self.source())
+        };
+        Ok(VerexValueParser::new(self.compile()?, description))
+    }
+
+    /// Start a new token, inserting the separator if the previous item was a token as well
+    #[track_caller]
+    fn open_token(&mut self) -> &mut Verex {
+        if self.after_token {
+            if let Some(separator) = self.separator.clone() {
+                self.open_part()
+                    .add(separator.as_ref());
+            }
+        }
+        self.after_token = true;
+        self.open_part()
+    }
+
+    /// Mark that the next token does not directly follow another one
+    #[track_caller]
+    fn close_token(&mut self) -> &mut Verex {
+        self.after_token = false;
+        self.open_part()
+    }
+
+    /// Start a new logical part of the builder string
+    #[track_caller]
+    fn open_part(&mut self) -> &mut Verex {
+        let location = if self.track_locations { Some(Location::caller()) } else { None };
+        self.parts.push(Part {
+            start: self.string.len(),
+            note: None,
+            location
+        });
+        self
+    }
+
+    /// Return the logical parts of the builder string together with their notes
+    fn part_strs(& self) -> Vec<(&str, Option<&str>)> {
+        let mut result = Vec::new();
+        for (i, part) in self.parts.iter().enumerate() {
+            let end = match self.parts.get(i + 1) {
+                Some(next) => next.start,
+                None => self.string.len(),
+            };
+            result.push((&self.string[part.start..end], part.note.as_ref().map(|note| note.as_ref())));
+        }
+        result
+    }
+
+    /// Rewrite every logical part of the builder string, keeping the parts and their notes
+    fn map_parts<F>(&mut self, mut rewrite: F) -> &mut Verex where F: FnMut(&str) -> String {
+        let rewritten: Vec<String> = self.part_strs().into_iter().map(|(string, _)| rewrite(string)).collect();
+        let mut string = String::with_capacity(self.string.len());
+        for (part, rewritten) in self.parts.iter_mut().zip(rewritten) {
+            part.start = string.len();
+            string.push_str(rewritten.as_ref());
+        }
+        self.string = string;
+        self.update_source_with_modifiers()
+    }
+
+    /// Return the flag group prefix of the source, e.g. `(?im:`
+    fn flags_prefix(& self) -> String {
+        let mut prefix = r"(?".to_owned();
+        if self.modifiers.contains(ModifierSet::CASE_INSENSITIVE) {
+            prefix.push('i');
+        }
+        if self.modifiers.contains(ModifierSet::MULTI_LINE) {
+            prefix.push('m');
+        }
+        prefix.push(':');
+        prefix
+    }
+
+    /// Escape a value unless escaping was disabled in the config
+    fn escape_value<'v>(& self, value: &'v str) -> Cow<'v, str> {
+        if self.escape_values {
+            escape(value)
+        }
+        else {
+            Cow::Borrowed(value)
+        }
+    }
+
+    /// Escape the characters of a character class unless escaping was disabled in the config, including `-`
+    fn escape_class_value<'v>(& self, chars: &'v str) -> Cow<'v, str> {
+        let escaped = self.escape_value(chars);
+        if self.escape_values && escaped.contains('-') {
+            Cow::Owned(escaped.replace('-', r"\x2D"))
+        }
+        else {
+            escaped
+        }
+    }
+
     /// Open a character class
     fn open_class(&mut self) -> &mut Verex {
         self.add(r"[")
@@ -165,119 +926,833 @@ impl Verex {
         self.add(r"(")
     }
 
+    /// Open the group of `anything()` and `something()`, which is capturing unless disabled
+    fn open_wildcard_group(&mut self) -> &mut Verex {
+        if self.capture_wildcards {
+            self.open_capturing_group()
+        }
+        else {
+            self.open_group()
+        }
+    }
+
     /// Close a capturing or non-capturing group
     fn close_group(&mut self) -> &mut Verex {
         self.add(r")")
     }
 
-    /// Open a quantifier
-    fn open_quantifier(&mut self) -> &mut Verex {
-        self.add(r"{")
+    /// Open a quantifier
+    fn open_quantifier(&mut self) -> &mut Verex {
+        self.group_last_token()
+            .add(r"{")
+    }
+
+    /// Wrap the whole builder string in a non-capturing group
+    fn group_all(&mut self) -> &mut Verex {
+        self.string.insert_str(0, r"(?:");
+        for part in self.parts.iter_mut().filter(|part| part.start > 0) {
+            part.start += 3;
+        }
+        self.close_group()
+    }
+
+    /// Wrap the last logical token in a non-capturing group unless a quantifier can be applied to it as is
+    fn group_last_token(&mut self) -> &mut Verex {
+        let start = self.parts.last().map(|part| part.start).unwrap_or(0);
+        if self.group_before_quantifiers && !is_single_item(&self.string[start..]) {
+            self.string.insert_str(start, r"(?:");
+            self.close_group();
+        }
+        self
+    }
+
+    /// Close a quantifier
+    fn close_quantifier(&mut self) -> &mut Verex {
+        self.add(r"}")
+    }
+
+    // --------------------------------------------------
+
+    /// Wrap everything built so far in a group and add a sub-expression (won't be escaped) as alternative
+    ///
+    /// Unlike `or_find_expr()` the alternative can not bind to only a part of the previous tokens.
+    #[track_caller]
+    pub fn alternate_with(&mut self, expr: Expression) -> &mut Verex {
+        if self.has_alternative() {
+            self.group_all();
+        }
+        self.or()
+            .find_expr(expr)
+    }
+
+    /// Any of the given characters
+    ///
+    /// All characters match literally, including `-` and `^`, so `"a-z"` matches `a`, `-` and `z`.
+    /// Use `any_set()` for ranges and negation.
+    #[track_caller]
+    pub fn any(&mut self, chars: &str) -> &mut Verex {
+        let chars = self.escape_class_value(chars);
+        self.open_token()
+            .open_class()
+            .add(chars.as_ref())
+            .close_class();
+        self.update_source_with_modifiers()
+    }
+
+    /// Add the class of the characters a sub-expression matches
+    #[track_caller]
+    fn any_expr_value(&mut self, value: &str) -> &mut Verex {
+        let class = expr_class(value, false);
+        self.open_token()
+            .add(class.as_ref());
+        self.update_source_with_modifiers()
+    }
+
+    /// Any of the characters in the set, e.g. `CharSet::new().range('a'..='z').chars("_-".chars())`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the set is empty.
+    #[track_caller]
+    pub fn any_set(&mut self, set: &CharSet) -> &mut Verex {
+        assert!(!set.is_empty(), "empty character set");
+        self.open_token()
+            .add(set.to_class().as_ref());
+        self.update_source_with_modifiers()
+    }
+
+    /// Any of the characters matched by a sub-expression, e.g. a class or an alternation of characters
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sub-expression matches anything but single characters.
+    #[track_caller]
+    pub fn any_expr(&mut self, expr: Expression) -> &mut Verex {
+        match_expr!(expr, self, any_expr_value)
+    }
+
+    /// See `any()`
+    #[track_caller]
+    pub fn any_of(&mut self, chars: &str) -> &mut Verex {
+        self.any(chars)
+    }
+
+    /// Toggle whether `digit()` only matches the ASCII digits 0-9 or all Unicode decimal digits
+    pub fn ascii_digits(&mut self, enable: bool) -> &mut Verex {
+        if enable {
+            self.modifiers.insert(ModifierSet::ASCII_DIGITS);
+        }
+        else {
+            self.modifiers.remove(ModifierSet::ASCII_DIGITS);
+        }
+        self.update_source_with_modifiers()
+    }
+
+    /// Toggle whether `word_char()`, `word()` and `whole_word()` only match ASCII letters, digits and `_`
+    pub fn ascii_words(&mut self, enable: bool) -> &mut Verex {
+        if enable {
+            self.modifiers.insert(ModifierSet::ASCII_WORDS);
+        }
+        else {
+            self.modifiers.remove(ModifierSet::ASCII_WORDS);
+        }
+        self.update_source_with_modifiers()
+    }
+
+    /// Toggle whether groups added by `capture()` get generated names (`cap_1`, `cap_2`, ...)
+    pub fn auto_capture(&mut self, enable: bool) -> &mut Verex {
+        self.auto_capture = enable;
+        self
+    }
+
+    /// Any character zero or more times
+    #[track_caller]
+    pub fn anything(&mut self) -> &mut Verex {
+        self.open_token()
+            .open_wildcard_group()
+            .add(r".*")
+            .close_group();
+        self.update_source_with_modifiers()
+    }
+
+    /// Add the negated class of the characters a sub-expression matches, repeated with `*`
+    #[track_caller]
+    fn anything_but_expr_value(&mut self, value: &str) -> &mut Verex {
+        let class = expr_class(value, true);
+        self.open_token()
+            .open_group()
+            .add(class.as_ref())
+            .add(r"*")
+            .close_group();
+        self.update_source_with_modifiers()
+    }
+
+    /// Any character zero or more times except the provided characters
+    #[track_caller]
+    pub fn anything_but(&mut self, chars: &str) -> &mut Verex {
+        let chars = self.escape_class_value(chars);
+        self.open_token()
+            .open_group()
+            .open_class()
+            .add(r"^")
+            .add(chars.as_ref())
+            .close_class()
+            .add(r"*")
+            .close_group();
+        self.update_source_with_modifiers()
+    }
+
+    /// Any character zero or more times except the characters matched by a sub-expression
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sub-expression matches anything but single characters.
+    #[track_caller]
+    pub fn anything_but_expr(&mut self, expr: Expression) -> &mut Verex {
+        match_expr!(expr, self, anything_but_expr_value)
+    }
+
+    /// Add the expression of another `Verex` as a group, carrying over the origins of its capturing groups
+    ///
+    /// The numbered groups of the other expression are shifted by the number of groups before it,
+    /// use `append_with_mapping()` to learn their new indices or `prefix_captures()` to name them.
+    #[track_caller]
+    pub fn append(&mut self, other: &Verex) -> &mut Verex {
+        self.append_with_mapping(other);
+        self
+    }
+
+    /// Like `append()`, but return the new index of each capturing group of the other expression
+    #[track_caller]
+    pub fn append_with_mapping(&mut self, other: &Verex) -> Vec<usize> {
+        let offset = count_capture_groups(self.string.as_ref());
+        self.find_value(other.source());
+        for step in other.capture_steps.iter() {
+            self.capture_steps.push(CaptureStep {
+                index: step.index + offset,
+                ..step.clone()
+            });
+        }
+        self.repeated_captures.extend(other.repeated_captures.iter().cloned());
+        (1..=count_capture_groups(other.string.as_ref())).map(|index| index + offset).collect()
+    }
+
+    /// Build a sub-expression in a closure and add it as an atomic group that is never backtracked into
+    /// (needs `compile_fancy()`)
+    #[cfg(feature = "fancy-regex")]
+    #[track_caller]
+    pub fn atomic_group<F>(&mut self, build: F) -> &mut Verex where F: FnOnce(&mut Verex) -> &mut Verex {
+        let mut inner = self.nested();
+        build(&mut inner);
+        let offset = count_capture_groups(self.string.as_ref());
+        self.open_token()
+            .add(r"(?>")
+            .add(inner.string.as_ref())
+            .close_group();
+        self.merge_nested(inner, offset);
+        self.update_source_with_modifiers()
+    }
+
+    /// Limit the number of backtracking steps a regex from `compile_fancy()` may take per match
+    /// before returning an error, to bound the matching time on untrusted input
+    #[cfg(feature = "fancy-regex")]
+    pub fn backtrack_limit(&mut self, limit: usize) -> &mut Verex {
+        self.backtrack_limit = Some(limit);
+        self
+    }
+
+    /// Text enclosed by the open and close characters with nested pairs balanced up to a depth
+    /// e.g. `balanced('(', ')', 2)` matches `(a)` and `(a(b)c)` but not `((()))`
+    #[track_caller]
+    pub fn balanced(&mut self, open: char, close: char, max_depth: u32) -> &mut Verex {
+        let content = format!(r"[^{}{}]", escape_class_char(open), escape_class_char(close));
+        let open = escape(open.to_string().as_ref()).into_owned();
+        let close = escape(close.to_string().as_ref()).into_owned();
+        let mut string = format!(r"{}{}*{}", open, content, close);
+        for _ in 1..max_depth {
+            string = format!(r"{}(?:{}|{})*{}", open, content, string, close);
+        }
+        self.find_value(string.as_ref())
+    }
+
+    /// Text enclosed by the open and close characters with nested pairs balanced at any depth
+    /// (needs `compile_fancy()`, which limits the recursion to 20 levels)
+    ///
+    /// This adds a named capturing group called `balanced<n>` where n counts up from 0.
+    #[cfg(feature = "fancy-regex")]
+    #[track_caller]
+    pub fn balanced_recursive(&mut self, open: char, close: char) -> &mut Verex {
+        let name = format!("balanced{}", self.string.matches(r"(?P<balanced").count());
+        let content = format!(r"[^{}{}]", escape_class_char(open), escape_class_char(close));
+        let string = format!(r"(?P<{name}>{open}(?:{content}|\g<{name}>)*{close})",
+                             name = name,
+                             open = escape(open.to_string().as_ref()),
+                             content = content,
+                             close = escape(close.to_string().as_ref()));
+        self.find_value(string.as_ref())
+    }
+
+    /// A line break!
+    #[track_caller]
+    pub fn br(&mut self) -> &mut Verex {
+        self.line_break()
+    }
+    /// Build a sub-expression in a closure and add it as alternative named after the kind of text it matches
+    ///
+    /// Consecutive branches are alternatives of each other in one group, `matched_branch()` and
+    /// `branch_matches()` report which one matched. The name has to be a valid group name and is
+    /// used for a named capturing group.
+    #[track_caller]
+    pub fn branch<F>(&mut self, name: &str, build: F) -> &mut Verex where F: FnOnce(&mut Verex) -> &mut Verex {
+        let mut inner = self.nested();
+        build(&mut inner);
+        // any other call adds a part, so the group is only reopened directly after a branch
+        if self.branch_group_end == Some((self.parts.len(), self.string.len())) {
+            // reopen the group of the previous branches
+            self.string.pop();
+            self.add(r"|");
+        }
+        else {
+            self.open_token()
+                .open_group();
+        }
+        self.branches.push(name.to_owned());
+        self.add(r"(?P<")
+            .add(name)
+            .add(r">");
+        let offset = count_capture_groups(self.string.as_ref());
+        self.add(inner.string.as_ref())
+            .close_group()
+            .close_group();
+        self.merge_nested(inner, offset);
+        self.branch_group_end = Some((self.parts.len(), self.string.len()));
+        self.update_source_with_modifiers()
+    }
+
+    /// Return the name of the branch that matched for every match in the text
+    #[cfg(feature = "regex")]
+    pub fn branch_matches(& self, text: &str) -> Result<Vec<(&str, usize, usize)>, Error> {
+        let regex = self.compile()?;
+        Ok(regex.captures_iter(text).filter_map(|captures| {
+            let (start, end) = captures.pos(0)?;
+            self.branches.iter()
+                .find(|branch| captures.name(branch).is_some())
+                .map(|branch| (branch.as_str(), start, end))
+        }).collect())
+    }
+
+
+    /// Add a token for a single byte, e.g. of a binary file signature
+    ///
+    /// Bytes above `0x7F` can only be matched by a regex from `compile_bytes()`.
+    #[track_caller]
+    pub fn byte(&mut self, byte: u8) -> &mut Verex {
+        self.open_token()
+            .add(format!(r"(?-u:\x{:02X})", byte).as_ref());
+        self.update_source_with_modifiers()
+    }
+
+    /// Add a token for any byte in the range from `from` to `to` (both included)
+    ///
+    /// Bytes above `0x7F` can only be matched by a regex from `compile_bytes()`.
+    #[track_caller]
+    pub fn byte_range(&mut self, from: u8, to: u8) -> &mut Verex {
+        self.open_token()
+            .add(format!(r"(?-u:[\x{:02X}-\x{:02X}])", from, to).as_ref());
+        self.update_source_with_modifiers()
+    }
+
+    /// Find a specific value and capture it
+    #[track_caller]
+    fn capture_value(&mut self, value: &str) -> &mut Verex {
+        self.open_token();
+        let name = if self.auto_capture {
+            Some(format!("cap_{}", self.capture_steps.len() + 1))
+        }
+        else {
+            None
+        };
+        self.capture_steps.push(CaptureStep {
+            index: count_capture_groups(self.string.as_ref()) + 1,
+            name: name.clone(),
+            location: Location::caller()
+        });
+        match name {
+            Some(name) => self.add(r"(?P<").add(name.as_ref()).add(r">"),
+            None => self.open_capturing_group(),
+        };
+        self.add(value)
+            .close_group();
+        self.update_source_with_modifiers()
+    }
+
+    /// Find a specific string and capture it (will be escaped)
+    #[track_caller]
+    pub fn capture(&mut self, value: &str) -> &mut Verex {
+        let value = self.escape_value(value);
+        self.capture_value(value.as_ref())
+    }
+
+    /// Find a sub-expression and capture it (won't be escaped)
+    #[track_caller]
+    pub fn capture_expr(&mut self, expr: Expression) -> &mut Verex {
+        match_expr!(expr, self, capture_value)
+    }
+
+    /// Return the `capture()` call that created the capturing group with the given index
+    pub fn capture_origin(& self, index: usize) -> Option<&CaptureStep> {
+        self.capture_steps.iter().find(|step| step.index == index)
+    }
+
+    #[track_caller]
+    fn capture_repeated_value(&mut self, value: &str, name: &str, n: u32, m: Option<u32>) -> &mut Verex {
+        self.capture_steps.push(CaptureStep {
+            index: count_capture_groups(self.string.as_ref()) + 1,
+            name: Some(name.to_owned()),
+            location: Location::caller()
+        });
+        self.repeated_captures.push((name.to_owned(), value.to_owned()));
+        self.open_token()
+            .add(r"(?P<")
+            .add(name)
+            .add(r">")
+            .open_group()
+            .add(value)
+            .close_group()
+            .add(r"{")
+            .add(quantifier_bounds(n, m).as_ref())
+            .close_quantifier()
+            .close_group();
+        self.update_source_with_modifiers()
+    }
+
+    /// Add a sub-expression (won't be escaped) repeated as often as the range allows and capture all
+    /// repetitions in a named group
+    ///
+    /// A regex only keeps the last repetition of a quantified group, `repetitions()` returns each of them.
+    #[track_caller]
+    pub fn capture_repeated<R: RangeBounds<u32>>(&mut self, name: &str, expr: Expression, bounds: R) -> &mut Verex {
+        let (n, m) = repetition_bounds(bounds);
+        match_expr!(expr, self, capture_repeated_value, name, n, m)
+    }
+
+    /// Return the `capture()` calls that created capturing groups in the order they were made
+    pub fn capture_steps(& self) -> &[CaptureStep] {
+        self.capture_steps.as_ref()
+    }
+
+    /// Toggle whether `anything()` and `something()` add capturing groups (enabled by default)
+    ///
+    /// Disabling this keeps the indices of groups added with `capture()` stable.
+    pub fn capture_wildcards(&mut self, enable: bool) -> &mut Verex {
+        self.capture_wildcards = enable;
+        self
+    }
+
+    /// Add a token for a carriage return (`\r`)
+    #[track_caller]
+    pub fn carriage_return(&mut self) -> &mut Verex {
+        self.open_token()
+            .add(r"\r");
+        self.update_source_with_modifiers()
+    }
+
+    /// Remove everything added to the expression, keeping the modifiers and settings
+    ///
+    /// The builder string keeps its capacity, so one `Verex` can be reused to build many patterns.
+    pub fn clear(&mut self) -> &mut Verex {
+        self.string.clear();
+        self.parts.clear();
+        self.after_token = false;
+        self.capture_steps.clear();
+        self.repeated_captures.clear();
+        self.branches.clear();
+        self.branch_group_end = None;
+        self.filters.clear();
+        self.update_source_with_modifiers()
+    }
+
+    /// Add the token for matching a control character, e.g. a tab, line break or `\0`
+    #[track_caller]
+    pub fn control_char(&mut self) -> &mut Verex {
+        self.open_token()
+            .add(r"\p{Cc}");
+        self.update_source_with_modifiers()
+    }
+
+    /// Attach a human-readable note to the most recently added part of the expression
+    /// (shown by `explain()`, `source_verbose()` and `explain_error()`)
+    pub fn describe(&mut self, note: &str) -> &mut Verex {
+        if let Some(part) = self.parts.last_mut() {
+            part.note = Some(note.to_owned());
+        }
+        self
+    }
+
+    /// Compare the expression part by part with a newer version, e.g. to review changes to matching rules
+    pub fn diff(& self, new: &Verex) -> VerexDiff {
+        fn parts(verex: &Verex) -> Vec<&str> {
+            verex.part_strs().into_iter().map(|(string, _)| string).filter(|string| !string.is_empty()).collect()
+        }
+        let old_flags = self.flags_prefix();
+        let new_flags = new.flags_prefix();
+        VerexDiff {
+            flags: if old_flags != new_flags { Some((old_flags, new_flags)) } else { None },
+            parts: diff::diff_parts(parts(self).as_ref(), parts(new).as_ref())
+        }
+    }
+
+    /// The token for a digit, depending on `ascii_digits()`
+    fn digit_token(& self) -> &'static str {
+        if self.modifiers.contains(ModifierSet::ASCII_DIGITS) {
+            r"[0-9]"
+        }
+        else {
+            r"\d"
+        }
+    }
+
+    /// Add the token for matching digits
+    #[track_caller]
+    pub fn digit(&mut self) -> &mut Verex {
+        let token = self.digit_token();
+        self.open_token()
+            .add(token);
+        self.update_source_with_modifiers()
+    }
+
+    /// Add the token for matching decimal digits of any script (e.g. Arabic-Indic or Devanagari)
+    #[track_caller]
+    pub fn digit_unicode(&mut self) -> &mut Verex {
+        self.open_token()
+            .add(r"\p{Nd}");
+        self.update_source_with_modifiers()
+    }
+
+    /// Add the token for matching exactly n digits
+    #[track_caller]
+    pub fn digits(&mut self, n: u32) -> &mut Verex {
+        self.digit()
+            .repeat_n(n)
+    }
+
+    /// Add the token for matching n to m digits
+    #[track_caller]
+    pub fn digits_between(&mut self, n: u32, m: u32) -> &mut Verex {
+        self.digit()
+            .repeat_n_to_m(n, m)
+    }
+
+    /// Whether the expression matches nowhere in the text, e.g. to make sure an input contains no forbidden characters
+    #[cfg(feature = "regex")]
+    pub fn does_not_match(& self, text: &str) -> Result<bool, Error> {
+        Ok(!self.compile()?.is_match(text))
+    }
+
+    /// Add a token for matching the end of a line
+    #[track_caller]
+    pub fn end_of_line(&mut self) -> &mut Verex {
+        self.close_token()
+            .add(r"$");
+        self.update_source_with_modifiers()
+    }
+
+    /// Whether both expressions match exactly the same whole texts, e.g. to check that a refactoring
+    /// did not change a pattern
+    ///
+    /// Both patterns are compiled to DFAs of at most 10 MiB each, larger patterns result in an error.
+    #[cfg(feature = "regex-automata")]
+    #[allow(clippy::result_large_err)]
+    pub fn equivalent_to(& self, other: &Verex) -> Result<bool, AnalysisError> {
+        analysis::equivalent(self.source(), other.source())
+    }
+
+    /// Find a specific string that has to make up a whole line (will be escaped)
+    #[track_caller]
+    pub fn exactly_line(&mut self, value: &str) -> &mut Verex {
+        self.line(|verex| verex.find(value))
+    }
+
+    /// List the parts of the expression line by line together with their notes
+    pub fn explain(& self) -> String {
+        let parts = self.part_strs();
+        let width = parts.iter().map(|&(string, _)| string.chars().count()).max().unwrap_or(0);
+        let mut lines = Vec::new();
+        for (string, note) in parts {
+            match note {
+                Some(note) => lines.push(format!("{:width$}  {}", string, note, width = width)),
+                None => lines.push(string.to_owned()),
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Describe a compile error of this expression, naming the part it occurred in (and the builder call
+    /// that added it if `track_locations()` is enabled)
+    ///
+    /// If the compiled expression is too big, the part that is too big on its own (or else the longest part)
+    /// is named together with ways to avoid the error. Syntax errors are only mapped to a part while the
+    /// source is not rewritten by `longest_match()` or case folding; otherwise the error is described as is.
+    #[cfg(feature = "regex")]
+    pub fn explain_error(& self, error: &Error) -> String {
+        let position = match *error {
+            Error::Syntax(ref syntax_error) => syntax_error.position(),
+            Error::CompiledTooBig(limit) => return self.explain_size_error(error, limit),
+            _ => return error.to_string(),
+        };
+        let prefix = self.flags_prefix();
+        let source = self.source();
+        // positions only map back to the parts if the source is the plain prefix, string and closing paren
+        let rewritten = !(source.starts_with(prefix.as_str()) && source[prefix.len()..].strip_suffix(')') == Some(self.string.as_str()));
+        let prefix_len = prefix.chars().count();
+        if rewritten || position < prefix_len {
+            return error.to_string();
+        }
+        let offset = match self.string.char_indices().nth(position - prefix_len) {
+            Some((offset, _)) => offset,
+            None => self.string.len(),
+        };
+        let part = self.parts.iter().rposition(|part| part.start <= offset);
+        match part {
+            Some(index) => self.explain_error_in_part(error, index),
+            None => error.to_string(),
+        }
+    }
+
+    /// Describe an error that occurred in the part with the given index
+    #[cfg(feature = "regex")]
+    fn explain_error_in_part(& self, error: &Error, index: usize) -> String {
+        let (string, note) = self.part_strs()[index];
+        let mut explained = match note {
+            Some(note) => format!("{} in `{}` ({})", error, string, note),
+            None => format!("{} in `{}`", error, string),
+        };
+        if let Some(location) = self.parts[index].location {
+            explained.push_str(format!(" added at {}", location).as_ref());
+        }
+        explained
+    }
+
+    /// Describe an error about the size limit, naming the part that makes the expression too big
+    #[cfg(feature = "regex")]
+    fn explain_size_error(& self, error: &Error, limit: usize) -> String {
+        let parts = self.part_strs();
+        let too_big = |string: &str| matches!(RegexBuilder::new(string).size_limit(limit).compile(), Err(Error::CompiledTooBig(_)));
+        let culprit = parts.iter().position(|&(string, _)| too_big(string)).or_else(|| {
+            parts.iter().enumerate().max_by_key(|&(_, &(string, _))| string.len()).map(|(index, _)| index)
+        });
+        let explained = match culprit {
+            Some(index) => self.explain_error_in_part(error, index),
+            None => error.to_string(),
+        };
+        format!("{}; raise the limit with `compile_with()`, reduce large repetition counts or split big alternations \
+                 into several expressions", explained)
+    }
+
+    /// Add a predicate that matches found by `find_valid_iter()` have to pass, e.g. a checksum
+    /// that can not be expressed as regex
+    ///
+    /// The filters are not kept when the `Verex` is used as sub-expression of another one.
+    pub fn filter<F>(&mut self, predicate: F) -> &mut Verex where F: Fn(&str) -> bool + Send + Sync + 'static {
+        self.filters.push(Filter::new(predicate));
+        self
+    }
+
+    /// Find a value
+    #[track_caller]
+    fn find_value(&mut self, value: &str) -> &mut Verex {
+        self.reserve_group(value)
+            .open_token()
+            .open_group()
+            .add(value)
+            .close_group();
+        self.update_source_with_modifiers()
+    }
+
+    /// Find a specific string that will be escaped
+    #[track_caller]
+    pub fn find(&mut self, value: &str) -> &mut Verex {
+        let value = self.escape_value(value);
+        self.find_value(value.as_ref())
+    }
+
+    /// Find a specific string regardless of the case of its letters without using the `i` flag
+    #[track_caller]
+    pub fn find_any_case(&mut self, value: &str) -> &mut Verex {
+        self.find_value(expand_any_case(value).as_ref())
     }
 
-    /// Close a quantifier
-    fn close_quantifier(&mut self) -> &mut Verex {
-        self.add(r"}")
+    /// Find a value that is escaped according to the mode, e.g. `EscapeMode::SmartWildcards` for
+    /// the input of a search box
+    #[track_caller]
+    pub fn find_with(&mut self, value: &str, mode: EscapeMode) -> &mut Verex {
+        self.find_value(mode.apply(value).as_ref())
     }
 
-    // --------------------------------------------------
-
-    /// Any of the given characters
-    pub fn any(&mut self, chars: &str) -> &mut Verex {
-        self.open_class()
-            .add(escape(chars).as_ref())
-            .close_class();
-        self.update_source_with_modifiers()
+    /// Find an expression (does not get escaped)
+    #[track_caller]
+    pub fn find_expr(&mut self, expr: Expression) -> &mut Verex {
+        match_expr!(expr, self, find_value)
     }
 
-    /// See `any()`
-    pub fn any_of(&mut self, chars: &str) -> &mut Verex {
-        self.any(chars)
+    /// Return the start and end of at most `limit` matches in the text, without searching the rest of it
+    #[cfg(feature = "regex")]
+    pub fn find_at_most(& self, text: &str, limit: usize) -> Result<Vec<(usize, usize)>, Error> {
+        Ok(self.find_iter_at_most(text, limit)?.collect())
     }
 
-    /// Any character zero or more times
-    pub fn anything(&mut self) -> &mut Verex {
-        self.add(r"(.*)");
-        self.update_source_with_modifiers()
+    /// Return an iterator over the start and end of at most `limit` matches in the text
+    #[cfg(feature = "regex")]
+    pub fn find_iter_at_most<'t>(& self, text: &'t str, limit: usize) -> Result<FindAtMost<'t>, Error> {
+        Ok(FindAtMost::new(self.compile()?, text, limit))
     }
 
-    /// Any character zero or more times except the provided characters
-    pub fn anything_but(&mut self, chars: &str) -> &mut Verex {
-        self.open_group()
-            .open_class()
-            .add(r"^")
-            .add(escape(chars).as_ref())
-            .close_class()
-            .add(r"*")
-            .close_group();
-        self.update_source_with_modifiers()
+    /// Return the first match in the text with its capturing groups, if any
+    #[cfg(feature = "regex")]
+    pub fn find_match<'t>(& self, text: &'t str) -> Result<Option<VerexMatch<'t>>, Error> {
+        let regex = self.compile()?;
+        Ok(regex.captures(text).map(|captures| VerexMatch::new(&regex, text, &captures)))
     }
 
-    /// A line break!
-    pub fn br(&mut self) -> &mut Verex {
-        self.line_break()
+    /// Return all matches in the text with their capturing groups
+    #[cfg(feature = "regex")]
+    pub fn find_matches<'t>(& self, text: &'t str) -> Result<Vec<VerexMatch<'t>>, Error> {
+        let regex = self.compile()?;
+        Ok(regex.captures_iter(text).map(|captures| VerexMatch::new(&regex, text, &captures)).collect())
     }
 
-    /// Find a specific value and capture it
-    fn capture_value(&mut self, value: &str) -> &mut Verex {
-        self.open_capturing_group()
-            .add(value)
-            .close_group();
-        self.update_source_with_modifiers()
+    /// Iterate over the start and end of the matches in the text that pass all filters added with `filter()`
+    #[cfg(feature = "regex")]
+    pub fn find_valid_iter<'t>(& self, text: &'t str) -> Result<FindValid<'t>, Error> {
+        let regex = self.compile()?;
+        Ok(FindValid::new(regex, self.filters.clone(), text))
     }
 
-    /// Find a specific string and capture it (will be escaped)
-    pub fn capture(&mut self, value: &str) -> &mut Verex {
-        self.capture_value(escape(value).as_ref())
+    /// Return a hash of the normalized expression and its flags that is the same on every platform
+    /// and with every build, e.g. to check that all nodes of a system use the same matching rules
+    ///
+    /// Expressions that can not be normalized are hashed as they are.
+    pub fn fingerprint(& self) -> u64 {
+        let string = normalize::normalize(self.string.as_ref()).unwrap_or_else(|_| self.string.clone());
+        fnv1a(self.flags_prefix().bytes().chain(string.bytes()))
     }
 
-    /// Find a sub-expression and capture it (won't be escaped)
-    pub fn capture_expr(&mut self, expr: Expression) -> &mut Verex {
-        match_expr!(expr, self, capture_value)
+    /// Return the `fingerprint()` as string of 16 hexadecimal digits
+    pub fn fingerprint_hex(& self) -> String {
+        format!("{:016x}", self.fingerprint())
     }
 
-    /// Add the token for matching digits
-    pub fn digit(&mut self) -> &mut Verex {
-        self.add(r"\d");
+    /// Set how `with_any_case()` makes the expression case-insensitive
+    ///
+    /// With `CaseFolding::Ascii` and `CaseFolding::Full` the letters are expanded to character classes
+    /// (and alternations for full case foldings) instead of setting the `i` flag. If the expression
+    /// cannot be parsed, the `i` flag is used.
+    pub fn fold_case(&mut self, folding: CaseFolding) -> &mut Verex {
+        self.case_folding = folding;
         self.update_source_with_modifiers()
     }
 
-    /// Add a token for matching the end of a line
-    pub fn end_of_line(&mut self) -> &mut Verex {
-        self.add(r"$");
+    /// A full extended grapheme cluster, e.g. a letter with its combining marks or an emoji sequence
+    ///
+    /// This approximates `\X` with the rules for CRLF, combining marks, emoji modifiers and ZWJ sequences,
+    /// pairs of regional indicators and Hangul syllables. Other rules like prepended characters
+    /// or Indic conjuncts are not applied.
+    #[cfg(feature = "grapheme")]
+    #[track_caller]
+    pub fn grapheme(&mut self) -> &mut Verex {
+        // the precomposed syllables without final consonant (LV) can be followed by more vowels,
+        // the ones with final consonant (LVT) only by final consonants
+        let lv: String = (0xAC00..=0xD7A3).step_by(28).filter_map(char::from_u32).collect();
+        let leading = r"[\x{1100}-\x{115F}\x{A960}-\x{A97C}]";
+        let vowel = r"[\x{1160}-\x{11A7}\x{D7B0}-\x{D7C6}]";
+        let trailing = r"[\x{11A8}-\x{11FF}\x{D7CB}-\x{D7FB}]";
+        let hangul = format!(r"{l}*(?:[{lv}]{v}*|[\x{{AC00}}-\x{{D7A3}}]|{v}+){t}*|{l}+|{t}+",
+                             l = leading, lv = lv, v = vowel, t = trailing);
+        let cluster = format!(r"\r\n|[\x{{1F1E6}}-\x{{1F1FF}}]{{2}}|(?:{}|\P{{M}})(?:\p{{M}}|[\x{{1F3FB}}-\x{{1F3FF}}]|\x{{200D}}\P{{M}})*",
+                              hangul);
+        self.open_token()
+            .open_group()
+            .add(cluster.as_ref())
+            .close_group();
         self.update_source_with_modifiers()
     }
 
-    /// Find a value
-    fn find_value(&mut self, value: &str) -> &mut Verex {
-        self.open_group()
-            .add(value)
-            .close_group();
+    /// Add a `\xNN` escape, which matches the character U+00NN (or the byte in `(?-u)` mode)
+    #[track_caller]
+    pub fn hex_escape(&mut self, byte: u8) -> &mut Verex {
+        self.open_token()
+            .add(format!(r"\x{:02X}", byte).as_ref());
         self.update_source_with_modifiers()
     }
 
-    /// Find a specific string that will be escaped
-    pub fn find(&mut self, value: &str) -> &mut Verex {
-        self.find_value(escape(value).as_ref())
+    /// Return whether nothing was added to the expression yet, regardless of the modifiers
+    pub fn is_empty(& self) -> bool {
+        self.string.is_empty()
     }
 
-    /// Find an expression (does not get escaped)
-    pub fn find_expr(&mut self, expr: Expression) -> &mut Verex {
-        match_expr!(expr, self, find_value)
+    /// Return the length in bytes of the source including the flag group, see `source()`
+    ///
+    /// The length is never zero, even an empty `Verex` has the source `(?:)`.
+    pub fn len(& self) -> usize {
+        self.source().len()
+    }
+
+    /// Build a sub-expression in a closure that has to make up a whole line (or the whole text
+    /// when searching one line only)
+    #[track_caller]
+    pub fn line<F>(&mut self, build: F) -> &mut Verex where F: FnOnce(&mut Verex) -> &mut Verex {
+        let mut inner = self.nested();
+        build(&mut inner);
+        let offset = count_capture_groups(self.string.as_ref());
+        self.start_of_line()
+            .find_value(inner.string.as_ref())
+            .end_of_line();
+        self.merge_nested(inner, offset);
+        self
     }
 
     /// A line break!
+    #[track_caller]
     pub fn line_break(&mut self) -> &mut Verex {
-        self.open_group()
-            .add(r"\n")
-            .or_find_expr(Expression::String(r"\r\n"))
+        let line_break = self.line_break.as_str();
+        self.open_token()
+            .open_group()
+            .add(line_break)
             .close_group();
         self.update_source_with_modifiers()
     }
 
+    /// Toggle whether an expression made of literal alternatives only, like `or!("in", "into")`, tries
+    /// longer alternatives first, so keywords are not cut off at a shorter one that is their prefix
+    ///
+    /// Expressions with other alternatives are left as they are.
+    pub fn longest_match(&mut self, enable: bool) -> &mut Verex {
+        self.longest_match = enable;
+        self.update_source_with_modifiers()
+    }
+
+    /// Return the name of the branch of the first match in the text, if any
+    #[cfg(feature = "regex")]
+    pub fn matched_branch(& self, text: &str) -> Result<Option<&str>, Error> {
+        let regex = self.compile()?;
+        Ok(regex.captures(text).and_then(|captures| {
+            self.branches.iter().find(|branch| captures.name(branch).is_some()).map(|branch| branch.as_str())
+        }))
+    }
+
+    /// Whether the expression matches the text at least `count` times, stopping the search at the
+    /// `count`th match
+    #[cfg(feature = "regex")]
+    pub fn matches_at_least(& self, text: &str, count: usize) -> Result<bool, Error> {
+        Ok(self.find_iter_at_most(text, count)?.count() == count)
+    }
+
     /// Any string either one or zero times
+    #[track_caller]
     fn maybe_value(&mut self, value: &str) -> &mut Verex {
-        self.open_group()
+        self.reserve_group(value)
+            .open_token()
+            .open_group()
             .add(value)
             .close_group()
             .add(r"?");
@@ -285,35 +1760,214 @@ impl Verex {
     }
 
     /// Any string either one or zero times
+    #[track_caller]
     pub fn maybe(&mut self, value: &str) -> &mut Verex {
-        self.maybe_value(escape(value).as_ref())
+        let value = self.escape_value(value);
+        self.maybe_value(value.as_ref())
     }
 
     /// Any string either one or zero times
+    #[track_caller]
     pub fn maybe_expr(&mut self, expr: Expression) -> &mut Verex {
         match_expr!(expr, self, maybe_value)
     }
 
+    /// A digit either one or zero times
+    #[track_caller]
+    pub fn maybe_digit(&mut self) -> &mut Verex {
+        let token = self.digit_token();
+        self.maybe_value(token)
+    }
+
+    /// A whitespace character either one or zero times
+    #[track_caller]
+    pub fn maybe_whitespace(&mut self) -> &mut Verex {
+        self.maybe_value(r"\s")
+    }
+
+    /// Alphanumeric characters (see `word()`) either one or zero times
+    #[track_caller]
+    pub fn maybe_word(&mut self) -> &mut Verex {
+        let token = self.word_token();
+        self.maybe_value(format!("{}+", token).as_ref())
+    }
+
+    /// Return the current modifiers
+    pub fn modifiers(& self) -> ModifierSet {
+        self.modifiers
+    }
+
+    /// Toggle whether ^ and $ match line start and end instead of string start and end (disabled by default)
+    pub fn multi_line(&mut self, enable: bool) -> &mut Verex {
+        self.modifiers.set(ModifierSet::MULTI_LINE, enable);
+        self.update_source_with_modifiers()
+    }
+
+    /// Add a token for a newline (`\n`), unlike `line_break()` without `\r`
+    #[track_caller]
+    pub fn newline(&mut self) -> &mut Verex {
+        self.open_token()
+            .add(r"\n");
+        self.update_source_with_modifiers()
+    }
+
+    /// Add the token for matching a character that is not printable: control and format characters,
+    /// surrogates, private use and unassigned code points
+    #[track_caller]
+    pub fn non_printable(&mut self) -> &mut Verex {
+        self.open_token()
+            .add(r"\p{C}");
+        self.update_source_with_modifiers()
+    }
+
+    /// Return a copy with the expression in a canonical form, so equivalent builder chains compare equal
+    #[cfg(feature = "regex")]
+    pub fn normalized(& self) -> Result<Verex, Error> {
+        let string = normalize::normalize(self.string.as_ref()).map_err(Error::Syntax)?;
+        let mut verex = self.clone();
+        verex.after_token = !string.is_empty();
+        verex.parts = if string.is_empty() {
+            Vec::new()
+        }
+        else {
+            vec![Part { start: 0, note: None, location: None }]
+        };
+        verex.string = string;
+        verex.update_source_with_modifiers();
+        Ok(verex)
+    }
+
+    /// Whether there is an alternative before the end, i.e. the builder string neither is empty nor ends with `or()`
+    fn has_alternative(& self) -> bool {
+        match self.part_strs().into_iter().map(|(part, _)| part).rfind(|part| !part.is_empty()) {
+            Some(part) => part != "|",
+            None => !self.string.is_empty(),
+        }
+    }
+
     /// Either match the sub-expression before or after this
+    ///
+    /// Without a sub-expression before, e.g. on an empty `Verex` or directly after another `or()`,
+    /// no empty alternative that would match everywhere is added.
+    #[track_caller]
     pub fn or(&mut self) -> &mut Verex {
-        self.add(r"|");
+        let has_alternative = self.has_alternative();
+        self.close_token();
+        if has_alternative {
+            self.add(r"|");
+        }
         self.update_source_with_modifiers()
     }
 
     /// Either match the sub-expression before or the provided value
+    #[track_caller]
     pub fn or_find(&mut self, value: &str) -> &mut Verex {
         self.or()
             .find(value)
     }
 
     /// Either match the sub-expression before or the provided sub-expression
+    #[track_caller]
     pub fn or_find_expr(&mut self, expr: Expression) -> &mut Verex {
         self.or()
             .find_expr(expr)
     }
 
+    /// Either match the sub-expression before or a sub-expression built in a closure
+    #[track_caller]
+    pub fn or_group<F>(&mut self, build: F) -> &mut Verex where F: FnOnce(&mut Verex) -> &mut Verex {
+        let mut inner = self.nested();
+        build(&mut inner);
+        let offset = count_capture_groups(self.string.as_ref());
+        self.or()
+            .find_value(inner.string.as_ref());
+        self.merge_nested(inner, offset);
+        self
+    }
+
+    /// Return the number of logical parts of the expression, i.e. the builder calls that added something
+    ///
+    /// These are the parts listed by `explain()` and compared by `diff()`.
+    pub fn part_count(& self) -> usize {
+        self.part_strs().into_iter().filter(|&(string, _)| !string.is_empty()).count()
+    }
+
+    /// Return all matches in each of the documents, searching the documents in parallel with one compiled regex
+    #[cfg(all(feature = "rayon", feature = "regex"))]
+    pub fn par_find_all<'d, S: AsRef<str> + Sync>(& self, docs: &'d [S]) -> Result<Vec<Vec<&'d str>>, Error> {
+        let regex = self.compile()?;
+        Ok(docs.par_iter().map(|doc| {
+            let doc = doc.as_ref();
+            regex.find_iter(doc).map(|(start, end)| &doc[start..end]).collect()
+        }).collect())
+    }
+
+    /// Return the lines the expression matches, testing the lines in parallel with one compiled regex
+    ///
+    /// The lines keep their order.
+    #[cfg(all(feature = "rayon", feature = "regex"))]
+    pub fn par_filter_matching<'l, S: AsRef<str> + Sync>(& self, lines: &'l [S]) -> Result<Vec<&'l str>, Error> {
+        let regex = self.compile()?;
+        Ok(lines.par_iter().map(|line| line.as_ref()).filter(|line| regex.is_match(line)).collect())
+    }
+
+    /// Turn all capturing groups into named groups starting with the prefix, e.g. before appending the expression to another one
+    ///
+    /// Named groups keep their name after the prefix, numbered groups are named after their index.
+    pub fn prefix_captures(&mut self, prefix: &str) -> &mut Verex {
+        let mut index = 0;
+        self.map_parts(|part| map_capture_groups(part, |name| {
+            index += 1;
+            match name {
+                Some(name) => format!("(?P<{}{}>", prefix, name),
+                None => format!("(?P<{}{}>", prefix, index),
+            }
+        }));
+        for step in self.capture_steps.iter_mut() {
+            step.name = Some(match step.name {
+                Some(ref name) => format!("{}{}", prefix, name),
+                None => format!("{}{}", prefix, step.index),
+            });
+        }
+        for repeated in self.repeated_captures.iter_mut() {
+            repeated.0 = format!("{}{}", prefix, repeated.0);
+        }
+        self
+    }
+
+    /// Add the token for matching a printable character, i.e. any character `non_printable()` does not match
+    #[track_caller]
+    pub fn printable(&mut self) -> &mut Verex {
+        self.open_token()
+            .add(r"\P{C}");
+        self.update_source_with_modifiers()
+    }
+
+    /// Add the token for matching a punctuation character of any script, e.g. `!`, `-` or `«`
+    #[track_caller]
+    pub fn punctuation(&mut self) -> &mut Verex {
+        self.open_token()
+            .add(r"\p{P}");
+        self.update_source_with_modifiers()
+    }
+
+    /// Add the token for matching an ASCII punctuation character, i.e. one `punctuation()` matches
+    #[track_caller]
+    pub fn punctuation_ascii(&mut self) -> &mut Verex {
+        self.open_token()
+            .add(r"[!-#%-*,-/:;?@\[-\]_\{\}]");
+        self.update_source_with_modifiers()
+    }
+
+    /// Add a fragment like a domain type implementing `VerexFragment`
+    pub fn push<F: VerexFragment + ?Sized>(&mut self, fragment: &F) -> &mut Verex {
+        fragment.render_into(self);
+        self
+    }
+
     /// A range of characters e.g. [A-Z]
     /// Usage example: verex.range(vec![('a', 'z'),('A', 'Z')])
+    #[track_caller]
     pub fn range(&mut self, range: Vec<(char, char)>) -> &mut Verex {
         let mut string = r"[".to_owned();
         for tuple in range {
@@ -324,10 +1978,62 @@ impl Verex {
             string.push(to);
         }
         string.push(']');
-        self.add(string.as_ref());
+        self.open_token()
+            .add(string.as_ref());
+        self.update_source_with_modifiers()
+    }
+
+    /// A range of characters with options, e.g. to add the case variants of the letters
+    ///
+    /// Unlike `range()` the ranges are checked and the characters escaped, so an error is
+    /// returned instead of an invalid expression.
+    #[track_caller]
+    pub fn range_with(&mut self, ranges: &[(char, char)], options: RangeOptions) -> Result<&mut Verex, RangeError> {
+        ranges::check(ranges, options)?;
+        let class = normalize::range_class(ranges, options.fold_case);
+        self.open_token()
+            .add(class.as_ref());
+        Ok(self.update_source_with_modifiers())
+    }
+
+    /// Add a sub-expression repeated within the bounds
+    #[track_caller]
+    fn repeat_value(&mut self, value: &str, n: u32, m: Option<u32>) -> &mut Verex {
+        self.open_token()
+            .open_group()
+            .add(value)
+            .close_group()
+            .open_quantifier()
+            .add(quantifier_bounds(n, m).as_ref())
+            .close_quantifier();
         self.update_source_with_modifiers()
     }
 
+    /// Rename a named capturing group, e.g. to match the names the code using a shared pattern expects
+    ///
+    /// Nothing changes if there is no group with the old name.
+    pub fn rename_capture(&mut self, old: &str, new: &str) -> &mut Verex {
+        self.map_parts(|part| map_capture_groups(part, |name| match name {
+            Some(name) if name == old => format!("(?P<{}>", new),
+            Some(name) => format!("(?P<{}>", name),
+            None => r"(".to_owned(),
+        }));
+        for step in self.capture_steps.iter_mut().filter(|step| step.name.as_ref().map(|name| name == old) == Some(true)) {
+            step.name = Some(new.to_owned());
+        }
+        for repeated in self.repeated_captures.iter_mut().filter(|repeated| repeated.0 == old) {
+            repeated.0 = new.to_owned();
+        }
+        self
+    }
+
+    /// Add a sub-expression (won't be escaped) repeated as often as the range allows, e.g. `2..=4` or `1..`
+    #[track_caller]
+    pub fn repeat_expr<R: RangeBounds<u32>>(&mut self, expr: Expression, bounds: R) -> &mut Verex {
+        let (n, m) = repetition_bounds(bounds);
+        match_expr!(expr, self, repeat_value, n, m)
+    }
+
     /// Repeat the previous item n times
     pub fn repeat_n(&mut self, n: u32) -> &mut Verex {
         self.open_quantifier()
@@ -346,9 +2052,28 @@ impl Verex {
         self.update_source_with_modifiers()
     }
 
+    /// Repeat the previous item n to m times without ever giving back repetitions
+    /// (needs `compile_fancy()`)
+    #[cfg(feature = "fancy-regex")]
+    pub fn repeat_n_to_m_possessive(&mut self, n: u32, m: u32) -> &mut Verex {
+        self.repeat_n_to_m(n, m)
+            .add(r"+");
+        self.update_source_with_modifiers()
+    }
+
     /// Repeat the previous item once or more times
     pub fn repeat_once_or_more(&mut self) -> &mut Verex {
-        self.add(r"+");
+        self.group_last_token()
+            .add(r"+");
+        self.update_source_with_modifiers()
+    }
+
+    /// Repeat the previous item once or more times without ever giving back repetitions
+    /// (needs `compile_fancy()`)
+    #[cfg(feature = "fancy-regex")]
+    pub fn repeat_once_or_more_possessive(&mut self) -> &mut Verex {
+        self.group_last_token()
+            .add(r"++");
         self.update_source_with_modifiers()
     }
 
@@ -359,76 +2084,347 @@ impl Verex {
 
     /// Repeat the previous item zero or more times
     pub fn repeat_zero_or_more(&mut self) -> &mut Verex {
-        self.add(r"*");
+        self.group_last_token()
+            .add(r"*");
+        self.update_source_with_modifiers()
+    }
+
+    /// Repeat the previous item zero or more times without ever giving back repetitions
+    /// (needs `compile_fancy()`)
+    #[cfg(feature = "fancy-regex")]
+    pub fn repeat_zero_or_more_possessive(&mut self) -> &mut Verex {
+        self.group_last_token()
+            .add(r"*+");
         self.update_source_with_modifiers()
     }
 
+    /// Return all repetitions of a group added with `capture_repeated()` for each match in the text
+    ///
+    /// The repeated sub-expression is run again over the text matched by the group. Matches in which
+    /// the group did not take part, as well as all matches for an unknown name, have no repetitions.
+    #[cfg(feature = "regex")]
+    pub fn repetitions<'t>(& self, text: &'t str, name: &str) -> Result<Vec<Vec<&'t str>>, Error> {
+        let regex = self.compile()?;
+        let inner = match self.repeated_captures.iter().find(|repeated| repeated.0 == name) {
+            Some(repeated) => Some(Regex::new(format!("{}{})", self.flags_prefix(), repeated.1).as_ref())?),
+            None => None,
+        };
+        Ok(regex.captures_iter(text).map(|captures| {
+            match (captures.name(name), inner.as_ref()) {
+                (Some(group), Some(inner)) => {
+                    inner.find_iter(group)
+                         .filter(|&(start, end)| start < end)
+                         .map(|(start, end)| &group[start..end])
+                         .collect()
+                },
+                _ => Vec::new(),
+            }
+        }).collect())
+    }
+
     /// Replace a substring
+    #[cfg(feature = "regex")]
     pub fn replace(& self, text: &str, replacement: &str) -> Result<String, Error> {
-        let regex = try!(self.compile());
+        let regex = self.compile()?;
         Ok(regex.replace(text, replacement))
     }
 
-    /// Toggle whether ^ and $ match line start and end or string start and end
+    /// Replace all matches by themselves with the text of a group (given by name or index) transformed
+    #[cfg(feature = "regex")]
+    fn replace_group_with<F>(& self, text: &str, group: &str, transform: F) -> Result<String, Error> where F: Fn(&str) -> String {
+        let regex = self.compile()?;
+        Ok(regex.replace_all(text, |captures: &Captures| {
+            let (start, end) = captures.pos(0).unwrap_or((0, 0));
+            let position = match group.parse::<usize>() {
+                Ok(index) => captures.pos(index),
+                Err(_) => regex.capture_names().position(|name| name == Some(group)).and_then(|index| captures.pos(index)),
+            };
+            match position {
+                Some((group_start, group_end)) => format!("{}{}{}", &text[start..group_start], transform(&text[group_start..group_end]), &text[group_end..end]),
+                None => text[start..end].to_owned(),
+            }
+        }))
+    }
+
+    /// Replace the text of a group (given by name or index) by its lowercase version in all matches
+    #[cfg(feature = "regex")]
+    pub fn replace_with_lowercase_group(& self, text: &str, group: &str) -> Result<String, Error> {
+        self.replace_group_with(text, group, str::to_lowercase)
+    }
+
+    /// Replace the text of a group (given by name or index) by its titlecase version in all matches,
+    /// e.g. `tHE quick` becomes `The Quick`
+    #[cfg(feature = "regex")]
+    pub fn replace_with_titlecase_group(& self, text: &str, group: &str) -> Result<String, Error> {
+        self.replace_group_with(text, group, titlecase)
+    }
+
+    /// Replace the text of a group (given by name or index) by its uppercase version in all matches
+    #[cfg(feature = "regex")]
+    pub fn replace_with_uppercase_group(& self, text: &str, group: &str) -> Result<String, Error> {
+        self.replace_group_with(text, group, str::to_uppercase)
+    }
+
+    /// Remove all modifiers, restoring the ones of a new `Verex`
+    ///
+    /// Like `set_modifiers()`, this only affects tokens added afterwards for `ASCII_DIGITS` and `ASCII_WORDS`.
+    pub fn reset_modifiers(&mut self) -> &mut Verex {
+        self.set_modifiers(ModifierSet::new())
+    }
+
+    /// Toggle whether ^ and $ match string start and end (the default) or line start and end
+    ///
+    /// An alias of `single_line()`, kept for compatibility.
     pub fn search_one_line(&mut self, enable: bool) -> &mut Verex {
-        if enable {
-            self.modifiers.remove(MULTI_LINE);
-        }
-        else {
-            self.modifiers.insert(MULTI_LINE);
-        }
+        self.single_line(enable)
+    }
+
+
+    /// Split a text into the alternating matched and unmatched segments covering all of it
+    #[cfg(feature = "regex")]
+    pub fn segments<'t>(& self, text: &'t str) -> Result<Segments<'t>, Error> {
+        let regex = self.compile()?;
+        Ok(Segments::new(regex, text))
+    }
+
+    /// Replace all modifiers, e.g. to restore the ones returned by `modifiers()`
+    ///
+    /// Like the methods toggling `ASCII_DIGITS` and `ASCII_WORDS`, this only affects tokens added afterwards.
+    pub fn set_modifiers(&mut self, modifiers: ModifierSet) -> &mut Verex {
+        self.modifiers = modifiers;
         self.update_source_with_modifiers()
     }
 
+    /// Return the source quoted for safe use as a single argument on the command line of the shell
+    pub fn shell_quoted(& self, shell: Shell) -> String {
+        shell::quote(self.source(), shell)
+    }
+
+    /// Toggle whether ^ and $ match string start and end instead of line start and end (enabled by default)
+    ///
+    /// The opposite of `multi_line()`, both set the same modifier.
+    pub fn single_line(&mut self, enable: bool) -> &mut Verex {
+        self.multi_line(!enable)
+    }
+
     /// Any character at least one time
+    #[track_caller]
     pub fn something(&mut self) -> &mut Verex {
-        self.add(r"(.+)");
+        self.open_token()
+            .open_wildcard_group()
+            .add(r".+")
+            .close_group();
+        self.update_source_with_modifiers()
+    }
+
+    /// Add the negated class of the characters a sub-expression matches, repeated with `+`
+    #[track_caller]
+    fn something_but_expr_value(&mut self, value: &str) -> &mut Verex {
+        let class = expr_class(value, true);
+        self.open_token()
+            .open_group()
+            .add(class.as_ref())
+            .add(r"+")
+            .close_group();
         self.update_source_with_modifiers()
     }
 
     /// Any character at least one time except for these characters
+    #[track_caller]
     pub fn something_but(&mut self, chars: &str) -> &mut Verex {
-        self.open_group()
+        let chars = self.escape_class_value(chars);
+        self.open_token()
+            .open_group()
             .open_class()
             .add(r"^")
-            .add(escape(chars).as_ref())
+            .add(chars.as_ref())
             .close_class()
             .add(r"+")
             .close_group();
         self.update_source_with_modifiers()
     }
 
+    /// Any character at least one time except the characters matched by a sub-expression
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sub-expression matches anything but single characters.
+    #[track_caller]
+    pub fn something_but_expr(&mut self, expr: Expression) -> &mut Verex {
+        match_expr!(expr, self, something_but_expr_value)
+    }
+
+    /// Add a token for a single space
+    #[track_caller]
+    pub fn space(&mut self) -> &mut Verex {
+        self.open_token()
+            .add(r" ");
+        self.update_source_with_modifiers()
+    }
+
     /// Add a token for the start of a line
+    #[track_caller]
     pub fn start_of_line(&mut self) -> &mut Verex {
-        self.add(r"^");
+        self.close_token()
+            .add(r"^");
+        self.update_source_with_modifiers()
+    }
+
+    /// Remove all matches from the text
+    #[cfg(feature = "regex")]
+    pub fn strip(& self, text: &str) -> Result<String, Error> {
+        let regex = self.compile()?;
+        Ok(regex.replace_all(text, ""))
+    }
+
+    /// Turn all capturing groups (numbered and named) into non-capturing groups
+    pub fn strip_captures(&mut self) -> &mut Verex {
+        self.map_parts(strip_capture_groups);
+        self.capture_steps.clear();
+        self
+    }
+    /// Remove all matches from the text without copying it to a new string
+    #[cfg(feature = "regex")]
+    pub fn strip_in_place(& self, text: &mut String) -> Result<(), Error> {
+        let regex = self.compile()?;
+        let matches: Vec<(usize, usize)> = regex.find_iter(text).filter(|&(start, end)| start < end).collect();
+        if matches.is_empty() {
+            return Ok(());
+        }
+        let mut bytes = mem::take(text).into_bytes();
+        let mut write = matches[0].0;
+        for (i, &(_, end)) in matches.iter().enumerate() {
+            let next = matches.get(i + 1).map(|&(start, _)| start).unwrap_or(bytes.len());
+            bytes.copy_within(end..next, write);
+            write += next - end;
+        }
+        bytes.truncate(write);
+        *text = String::from_utf8(bytes).expect("matches start and end at character boundaries");
+        Ok(())
+    }
+
+
+    /// Add the token for matching a symbol of any script, e.g. `+`, `$` or `€`
+    #[track_caller]
+    pub fn symbol(&mut self) -> &mut Verex {
+        self.open_token()
+            .add(r"\p{S}");
+        self.update_source_with_modifiers()
+    }
+
+    /// Add the token for matching an ASCII symbol, i.e. one `symbol()` matches
+    #[track_caller]
+    pub fn symbol_ascii(&mut self) -> &mut Verex {
+        self.open_token()
+            .add(r"[\$\+<->\^`\|~]");
         self.update_source_with_modifiers()
     }
 
     /// Add a token for a tab
+    #[track_caller]
     pub fn tab(&mut self) -> &mut Verex {
-        self.add(r"\t");
+        self.open_token()
+            .add(r"\t");
         self.update_source_with_modifiers()
     }
 
+    /// Toggle whether optional whitespace is allowed between subsequently added tokens
+    pub fn tolerate_whitespace(&mut self, enable: bool) -> &mut Verex {
+        if enable {
+            self.separator = Some(r"\s*".to_owned());
+        }
+        else {
+            self.separator = None;
+        }
+        self
+    }
+
+    /// Toggle whether the source location of each subsequent builder call is recorded, so
+    /// `explain_error()` can name the call that added the part an error occurred in
+    pub fn track_locations(&mut self, enable: bool) -> &mut Verex {
+        self.track_locations = enable;
+        self
+    }
+
     /// To use find "in the sentence" and make the chaining flow better
+    #[track_caller]
     pub fn then(&mut self, value: &str) -> &mut Verex {
         self.find(value)
     }
 
+    /// Render the expression as POSIX extended regular expression, e.g. for `grep -E` or the `~` operator
+    /// of SQL databases
+    ///
+    /// Non-capturing groups become capturing groups and case-insensitive letters become bracket expressions.
+    pub fn to_posix_ere(& self) -> Result<String, ExportError> {
+        dialect::render(self.source(), Dialect::PosixEre)
+    }
+
+    /// Render the expression as pattern for the SQL `SIMILAR TO` operator
+    ///
+    /// As `SIMILAR TO` always matches the whole text, `%` is added unless the expression is anchored
+    /// with `start_of_line()` or `end_of_line()`.
+    pub fn to_sql_similar_to(& self) -> Result<String, ExportError> {
+        dialect::render(self.source(), Dialect::SimilarTo)
+    }
+
     /// Toggle whether to match case-sensitively or not
     pub fn with_any_case(&mut self, enable: bool) -> &mut Verex {
         if enable {
-            self.modifiers.insert(CASE_INSENSITIVE);
+            self.modifiers.insert(ModifierSet::CASE_INSENSITIVE);
         }
         else {
-            self.modifiers.remove(CASE_INSENSITIVE);
+            self.modifiers.remove(ModifierSet::CASE_INSENSITIVE);
         }
         self.update_source_with_modifiers()
     }
 
+    /// Any alphanumeric characters bounded by word boundaries on both sides
+    #[track_caller]
+    pub fn whole_word(&mut self) -> &mut Verex {
+        if self.modifiers.contains(ModifierSet::ASCII_WORDS) {
+            self.find_expr(Expression::String(r"(?-u:\b\w+\b)"))
+        }
+        else {
+            self.find_expr(Expression::String(r"\b\w+\b"))
+        }
+    }
+
+    /// Set the separator to insert between subsequently added tokens
+    fn separator_value(&mut self, value: &str) -> &mut Verex {
+        self.separator = Some(format!(r"(?:{})", value));
+        self
+    }
+
+    /// Allow the given sub-expression between subsequently added tokens (does not get escaped)
+    pub fn with_separator(&mut self, expr: Expression) -> &mut Verex {
+        match_expr!(expr, self, separator_value)
+    }
+
     /// Any alphanumeric characters
+    #[track_caller]
     pub fn word(&mut self) -> &mut Verex {
-        self.find_expr(Expression::String(r"\w+"))
+        let token = self.word_token();
+        self.find_expr(Expression::String(format!("{}+", token).as_ref()))
+    }
+
+    /// A single alphanumeric character
+    #[track_caller]
+    pub fn word_char(&mut self) -> &mut Verex {
+        let token = self.word_token();
+        self.open_token()
+            .add(token);
+        self.update_source_with_modifiers()
+    }
+
+    /// The token for a word character, depending on `ascii_words()`
+    fn word_token(& self) -> &'static str {
+        if self.modifiers.contains(ModifierSet::ASCII_WORDS) {
+            r"(?-u:\w)"
+        }
+        else {
+            r"\w"
+        }
     }
 }
 
@@ -440,8 +2436,44 @@ impl fmt::Display for Verex {
     }
 }
 
+/// The source including the flags, so a `&Verex` can be passed wherever a pattern string is expected
+impl AsRef<str> for Verex {
+    fn as_ref(&self) -> &str {
+        self.source()
+    }
+}
+
+impl Default for Verex {
+    fn default() -> Verex {
+        Verex::new()
+    }
+}
+
 impl Eq for Verex {}
 
+#[cfg(feature = "regex")]
+use std::convert::TryFrom;
+
+/// Compile the `Verex`, see `Verex::compile()`
+#[cfg(feature = "regex")]
+impl TryFrom<Verex> for Regex {
+    type Error = Error;
+
+    fn try_from(verex: Verex) -> Result<Regex, Error> {
+        verex.compile()
+    }
+}
+
+/// Compile the `Verex`, see `Verex::compile()`
+#[cfg(feature = "regex")]
+impl<'a> TryFrom<&'a Verex> for Regex {
+    type Error = Error;
+
+    fn try_from(verex: &'a Verex) -> Result<Regex, Error> {
+        verex.compile()
+    }
+}
+
 use std::str::FromStr;
 pub enum Void {}
 
@@ -460,8 +2492,30 @@ impl PartialEq for Verex {
     }
 }
 
+/// Comparison with a pattern string is based on the source including the flags
+impl PartialEq<str> for Verex {
+    fn eq(&self, other: &str) -> bool {
+        self.source() == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for Verex {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.source() == *other
+    }
+}
+
+/// Comparison with a compiled regex is based on the source including the flags
+#[cfg(feature = "regex")]
+impl PartialEq<Regex> for Verex {
+    fn eq(&self, other: &Regex) -> bool {
+        self.source() == other.as_str()
+    }
+}
+
 
 // In order to test the macro it has to be in scope...
+#[cfg(feature = "regex")]
 #[test]
 fn test_match_expr() {
     let string = "a string";
@@ -0,0 +1,369 @@
+use std::cmp::Reverse;
+
+use regex_syntax::{CharClass, ClassRange, Expr, Repeater};
+
+use super::escape_class_char;
+use super::CaseFolding;
+
+/// Parse a regex string and render it in a canonical form
+///
+/// Non-capturing groups are only kept where they are needed, adjacent literals are merged
+/// and character classes are sorted and merged.
+pub fn normalize(pattern: &str) -> Result<String, regex_syntax::Error> {
+    let expr = simplify(Expr::parse(pattern)?);
+    let mut result = String::new();
+    render(&expr, &mut result);
+    Ok(result)
+}
+
+/// Parse a regex string that only matches single characters and render the class of these characters
+/// (or of all other characters if `negated`), or return `None` if it matches anything else
+pub fn char_class(pattern: &str, negated: bool) -> Option<String> {
+    let class = Expr::parse(pattern).ok().and_then(class_of)?;
+    let mut result = String::new();
+    render_class(&if negated { class.negate() } else { class }, &mut result);
+    Some(result)
+}
+
+/// Render the class of the ranges, adding the case variants of their letters if `fold_case`
+pub fn range_class(ranges: &[(char, char)], fold_case: bool) -> String {
+    let class = merge_ranges(ranges.iter().map(|&(start, end)| ClassRange { start, end }).collect());
+    let mut result = String::new();
+    render_class(&if fold_case { class.case_fold() } else { class }, &mut result);
+    result
+}
+
+fn class_of(expr: Expr) -> Option<CharClass> {
+    match expr {
+        Expr::Class(class) => Some(class),
+        Expr::Literal { ref chars, casei } if chars.len() == 1 => {
+            let class = CharClass::new(vec![ClassRange { start: chars[0], end: chars[0] }]);
+            Some(if casei { class.case_fold() } else { class })
+        },
+        Expr::AnyChar => Some(CharClass::new(vec![ClassRange { start: '\0', end: '\u{10FFFF}' }])),
+        Expr::AnyCharNoNL => Some(CharClass::new(vec![ClassRange { start: '\n', end: '\n' }]).negate()),
+        Expr::Group { e, .. } => class_of(*e),
+        Expr::Alternate(es) => {
+            let mut ranges: Vec<ClassRange> = Vec::new();
+            for class in es.into_iter().map(class_of) {
+                ranges.extend(class?.iter().cloned());
+            }
+            Some(merge_ranges(ranges))
+        },
+        _ => None,
+    }
+}
+
+/// Sort and merge overlapping or adjacent ranges into a class
+fn merge_ranges(mut ranges: Vec<ClassRange>) -> CharClass {
+    ranges.sort_by_key(|range| range.start);
+    let mut merged: Vec<ClassRange> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start as u32 <= last.end as u32 + 1 => {
+                if range.end > last.end {
+                    last.end = range.end;
+                }
+            },
+            _ => merged.push(range),
+        }
+    }
+    CharClass::new(merged)
+}
+
+/// Parse a regex string and expand its letters to all their case variants instead of relying on the `i` flag
+///
+/// `CaseFolding::Ascii` only expands ASCII letters, `CaseFolding::Full` also expands letters whose case
+/// variants have several characters, e.g. `ß` to `(?:[ßẞ]|[Ssſ][Ssſ])`.
+pub fn fold_case(pattern: &str, folding: CaseFolding) -> Result<String, regex_syntax::Error> {
+    let expr = fold_expr(simplify(Expr::parse(pattern)?), folding);
+    let mut result = String::new();
+    render(&expr, &mut result);
+    Ok(result)
+}
+
+fn fold_expr(expr: Expr, folding: CaseFolding) -> Expr {
+    match expr {
+        Expr::Literal { chars, casei: false } => {
+            Expr::Concat(chars.into_iter().map(|c| fold_char(c, folding)).collect())
+        },
+        Expr::Class(class) => Expr::Class(fold_class(class, folding)),
+        Expr::Group { e, i, name } => Expr::Group { e: Box::new(fold_expr(*e, folding)), i, name },
+        Expr::Repeat { e, r, greedy } => Expr::Repeat { e: Box::new(fold_expr(*e, folding)), r, greedy },
+        Expr::Concat(es) => Expr::Concat(es.into_iter().map(|e| fold_expr(e, folding)).collect()),
+        Expr::Alternate(es) => Expr::Alternate(es.into_iter().map(|e| fold_expr(e, folding)).collect()),
+        e => e,
+    }
+}
+
+fn fold_char(c: char, folding: CaseFolding) -> Expr {
+    let single = CharClass::new(vec![ClassRange { start: c, end: c }]);
+    let class = fold_class(single, folding);
+    if class.len() == 1 && class[0].start == class[0].end {
+        return Expr::Literal { chars: vec![c], casei: false };
+    }
+    if folding != CaseFolding::Full {
+        return Expr::Class(class);
+    }
+    // case variants with several characters, like `SS` for `ß`, become alternatives
+    let mut alternatives = vec![Expr::Class(class)];
+    let mut variants: Vec<String> = Vec::new();
+    for variant in [c.to_lowercase().collect::<String>(), c.to_uppercase().collect::<String>()].iter() {
+        if variant.chars().count() > 1 && !variants.iter().any(|known| known.to_lowercase() == variant.to_lowercase()) {
+            variants.push(variant.clone());
+            alternatives.push(Expr::Concat(variant.chars().map(|c| fold_char(c, CaseFolding::Simple)).collect()));
+        }
+    }
+    if alternatives.len() == 1 {
+        alternatives.pop().unwrap()
+    }
+    else {
+        Expr::Alternate(alternatives)
+    }
+}
+
+fn fold_class(class: CharClass, folding: CaseFolding) -> CharClass {
+    if folding != CaseFolding::Ascii {
+        return class.case_fold();
+    }
+    let mut ranges: Vec<ClassRange> = class.iter().cloned().collect();
+    for range in class.iter() {
+        for &(from, to) in [('a', 'A'), ('A', 'a')].iter() {
+            let start = if range.start > from { range.start } else { from };
+            let end = if range.end < (from as u8 + 25) as char { range.end } else { (from as u8 + 25) as char };
+            if start <= end {
+                let shift = |c: char| (c as u8 - from as u8 + to as u8) as char;
+                ranges.push(ClassRange { start: shift(start), end: shift(end) });
+            }
+        }
+    }
+    merge_ranges(ranges)
+}
+
+/// Split a regex string at the `|` that are not nested in a group or class
+fn split_alternatives(pattern: &str) -> Vec<&str> {
+    let mut alternatives = Vec::new();
+    let mut depth = 0;
+    let mut in_class = false;
+    let mut start = 0;
+    let mut chars = pattern.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => { chars.next(); },
+            '[' => in_class = true,
+            ']' => in_class = false,
+            '(' if !in_class => depth += 1,
+            ')' if !in_class => depth -= 1,
+            '|' if !in_class && depth == 0 => {
+                alternatives.push(&pattern[start..i]);
+                start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    alternatives.push(&pattern[start..]);
+    alternatives
+}
+
+/// Return the number of characters of a regex string that only matches a literal
+fn literal_len(pattern: &str) -> Option<usize> {
+    match simplify(Expr::parse(pattern).ok()?) {
+        Expr::Literal { chars, .. } => Some(chars.len()),
+        Expr::Empty => Some(0),
+        _ => None,
+    }
+}
+
+/// Reorder the alternatives of a regex string that only consists of literal alternatives,
+/// so longer literals are tried before their prefixes, or return `None` for other regex strings
+pub fn longest_first(pattern: &str) -> Option<String> {
+    let alternatives = split_alternatives(pattern);
+    if alternatives.len() < 2 {
+        return None;
+    }
+    let mut lengths = alternatives.iter().map(|alternative| literal_len(alternative).map(|len| (len, *alternative)))
+                                  .collect::<Option<Vec<(usize, &str)>>>()?;
+    lengths.sort_by_key(|&(len, _)| Reverse(len));
+    Some(lengths.into_iter().map(|(_, alternative)| alternative).collect::<Vec<&str>>().join("|"))
+}
+
+/// Remove redundant groups and merge nested sequences and adjacent literals
+pub fn simplify(expr: Expr) -> Expr {
+    match expr {
+        Expr::Group { e, i: None, name: None } => simplify(*e),
+        Expr::Group { e, i, name } => Expr::Group { e: Box::new(simplify(*e)), i, name },
+        Expr::Repeat { e, r, greedy } => Expr::Repeat { e: Box::new(simplify(*e)), r, greedy },
+        Expr::Concat(es) => {
+            let mut result: Vec<Expr> = Vec::new();
+            for e in es.into_iter().map(simplify) {
+                let flattened = match e {
+                    Expr::Concat(inner) => inner,
+                    Expr::Empty => Vec::new(),
+                    e => vec![e],
+                };
+                for e in flattened {
+                    if let (Some(&mut Expr::Literal { chars: ref mut last_chars, casei: last_casei }),
+                            &Expr::Literal { ref chars, casei }) = (result.last_mut(), &e) {
+                        if last_casei == casei {
+                            last_chars.extend(chars.iter().cloned());
+                            continue;
+                        }
+                    }
+                    result.push(e);
+                }
+            }
+            match result.len() {
+                0 => Expr::Empty,
+                1 => result.pop().unwrap(),
+                _ => Expr::Concat(result),
+            }
+        },
+        Expr::Alternate(es) => {
+            let mut result = Vec::new();
+            for e in es.into_iter().map(simplify) {
+                match e {
+                    Expr::Alternate(inner) => result.extend(inner),
+                    e => result.push(e),
+                }
+            }
+            Expr::Alternate(result)
+        },
+        e => e,
+    }
+}
+
+/// Escape a character outside of a character class
+fn render_char(c: char, result: &mut String) {
+    if c.is_control() || (c.is_whitespace() && c != ' ') {
+        result.push_str(format!(r"\x{{{:X}}}", c as u32).as_ref());
+    }
+    else {
+        result.push_str(regex_syntax::quote(c.to_string().as_ref()).as_ref());
+    }
+}
+
+/// Escape a character inside of a character class
+fn render_class_char(c: char, result: &mut String) {
+    if c.is_control() || c.is_whitespace() || c == '\u{10FFFF}' {
+        result.push_str(format!(r"\x{{{:X}}}", c as u32).as_ref());
+    }
+    else {
+        result.push_str(escape_class_char(c).as_ref());
+    }
+}
+
+fn render_class(class: &CharClass, result: &mut String) {
+    for shorthand in [r"\d", r"\D", r"\w", r"\W", r"\s", r"\S"].iter() {
+        if let Ok(Expr::Class(ref known)) = Expr::parse(shorthand) {
+            if known == class {
+                result.push_str(shorthand);
+                return;
+            }
+        }
+    }
+    let covers_all = class.first().map(|range| range.start) == Some('\0')
+                     && class.last().map(|range| range.end) == Some('\u{10FFFF}');
+    let ranges = if covers_all {
+        result.push_str(r"[^");
+        class.clone().negate()
+    }
+    else {
+        result.push('[');
+        class.clone()
+    };
+    for range in ranges.iter() {
+        render_class_char(range.start, result);
+        if range.end != range.start {
+            result.push('-');
+            render_class_char(range.end, result);
+        }
+    }
+    result.push(']');
+}
+
+fn render_repeater(repeater: &Repeater, result: &mut String) {
+    match *repeater {
+        Repeater::ZeroOrOne => result.push('?'),
+        Repeater::ZeroOrMore => result.push('*'),
+        Repeater::OneOrMore => result.push('+'),
+        Repeater::Range { min, max: None } => result.push_str(format!("{{{},}}", min).as_ref()),
+        Repeater::Range { min, max: Some(max) } if min == max => result.push_str(format!("{{{}}}", min).as_ref()),
+        Repeater::Range { min, max: Some(max) } => result.push_str(format!("{{{},{}}}", min, max).as_ref()),
+    }
+}
+
+/// Render an expression wrapped in a non-capturing group if it is not a single item
+fn render_atom(expr: &Expr, result: &mut String) {
+    let needs_group = match *expr {
+        Expr::Literal { ref chars, casei: false } => chars.len() != 1,
+        Expr::Empty | Expr::Concat(_) | Expr::Alternate(_) | Expr::Repeat { .. } => true,
+        _ => false,
+    };
+    if needs_group {
+        result.push_str(r"(?:");
+        render(expr, result);
+        result.push(')');
+    }
+    else {
+        render(expr, result);
+    }
+}
+
+fn render(expr: &Expr, result: &mut String) {
+    match *expr {
+        Expr::Empty => {},
+        Expr::Literal { ref chars, casei } => {
+            if casei {
+                result.push_str(r"(?i:");
+            }
+            for &c in chars {
+                render_char(c, result);
+            }
+            if casei {
+                result.push(')');
+            }
+        },
+        Expr::AnyChar => result.push_str(r"(?s:.)"),
+        Expr::AnyCharNoNL => result.push('.'),
+        Expr::Class(ref class) => render_class(class, result),
+        Expr::StartLine => result.push_str(r"(?m:^)"),
+        Expr::EndLine => result.push_str(r"(?m:$)"),
+        Expr::StartText => result.push('^'),
+        Expr::EndText => result.push('$'),
+        Expr::WordBoundary => result.push_str(r"\b"),
+        Expr::NotWordBoundary => result.push_str(r"\B"),
+        Expr::Group { ref e, ref name, .. } => {
+            match *name {
+                Some(ref name) => result.push_str(format!(r"(?P<{}>", name).as_ref()),
+                None => result.push('('),
+            }
+            render(e, result);
+            result.push(')');
+        },
+        Expr::Repeat { ref e, ref r, greedy } => {
+            render_atom(e, result);
+            render_repeater(r, result);
+            if !greedy {
+                result.push('?');
+            }
+        },
+        Expr::Concat(ref es) => {
+            for e in es {
+                match *e {
+                    Expr::Alternate(_) => render_atom(e, result),
+                    _ => render(e, result),
+                }
+            }
+        },
+        Expr::Alternate(ref es) => {
+            for (i, e) in es.iter().enumerate() {
+                if i > 0 {
+                    result.push('|');
+                }
+                render(e, result);
+            }
+        },
+        // byte based expressions only occur with the `u` flag disabled
+        ref e => result.push_str(e.to_string().as_ref()),
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::VecDeque;
+
+use regex::{Error, Regex};
+
+use super::Verex;
+
+/// A stage of a `Pipeline`
+#[derive(Debug, Clone)]
+enum Stage {
+    /// Keep the lines that match (`true`) or do not match (`false`)
+    Filter(Regex, bool),
+    /// Replace all matches
+    Replace(Regex, String),
+    /// Turn every match into a line of its own
+    Extract(Regex)
+}
+
+impl Stage {
+    fn apply(& self, line: String, output: &mut Vec<String>) {
+        match *self {
+            Stage::Filter(ref regex, keep) => if regex.is_match(line.as_ref()) == keep {
+                output.push(line);
+            },
+            Stage::Replace(ref regex, ref replacement) => output.push(regex.replace_all(line.as_ref(), replacement.as_str())),
+            Stage::Extract(ref regex) => for captures in regex.captures_iter(line.as_ref()) {
+                // the first group if there is one, the whole match otherwise
+                if let Some(text) = captures.at(1).or_else(|| captures.at(0)) {
+                    output.push(text.to_owned());
+                }
+            },
+        }
+    }
+}
+
+/// Stages defined by patterns that are applied to lines of text one after another, e.g. for ETL scripts
+///
+/// ```rust
+/// # extern crate verex;
+/// use verex::{Pipeline, Verex};
+///
+/// # fn main() {
+///     let mut pipeline = Pipeline::new();
+///     pipeline.filter_matching(Verex::new().find("ERROR")).unwrap()
+///             .replace(Verex::new().digit().repeat_once_or_more(), "#").unwrap();
+///     let output: Vec<String> = pipeline.run(vec!["ERROR 404", "INFO 200"]).collect();
+///     assert_eq!(output, vec!["ERROR #"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Pipeline {
+    stages: Vec<Stage>
+}
+
+impl Pipeline {
+    /// Create a pipeline without stages, which passes all lines through
+    pub fn new() -> Pipeline {
+        Pipeline::default()
+    }
+
+    /// Add a stage that drops the lines without a match
+    pub fn filter_matching(&mut self, verex: &Verex) -> Result<&mut Pipeline, Error> {
+        self.stages.push(Stage::Filter(verex.compile()?, true));
+        Ok(self)
+    }
+
+    /// Add a stage that drops the lines with a match
+    pub fn filter_not_matching(&mut self, verex: &Verex) -> Result<&mut Pipeline, Error> {
+        self.stages.push(Stage::Filter(verex.compile()?, false));
+        Ok(self)
+    }
+
+    /// Add a stage that replaces all matches, the replacement can refer to groups like `Regex::replace_all()`
+    pub fn replace(&mut self, verex: &Verex, replacement: &str) -> Result<&mut Pipeline, Error> {
+        self.stages.push(Stage::Replace(verex.compile()?, replacement.to_owned()));
+        Ok(self)
+    }
+
+    /// Add a stage that replaces every line by its matches, one line per match
+    ///
+    /// If the pattern has capturing groups the text of the first one is extracted instead of the whole match.
+    pub fn extract(&mut self, verex: &Verex) -> Result<&mut Pipeline, Error> {
+        self.stages.push(Stage::Extract(verex.compile()?));
+        Ok(self)
+    }
+
+    /// Run a line through all stages and return the resulting lines
+    pub fn apply(& self, line: &str) -> Vec<String> {
+        let mut lines = vec![line.to_owned()];
+        for stage in &self.stages {
+            let mut output = Vec::new();
+            for line in lines {
+                stage.apply(line, &mut output);
+            }
+            lines = output;
+        }
+        lines
+    }
+
+    /// Run all lines through the pipeline lazily
+    pub fn run<I>(& self, lines: I) -> PipelineOutput<'_, I::IntoIter> where I: IntoIterator, I::Item: AsRef<str> {
+        PipelineOutput {
+            pipeline: self,
+            lines: lines.into_iter(),
+            pending: VecDeque::new()
+        }
+    }
+}
+
+/// Iterator over the output lines of `Pipeline::run()`
+#[derive(Debug)]
+pub struct PipelineOutput<'p, I> {
+    pipeline: &'p Pipeline,
+    lines: I,
+    pending: VecDeque<String>
+}
+
+impl<'p, I> Iterator for PipelineOutput<'p, I> where I: Iterator, I::Item: AsRef<str> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        loop {
+            if let Some(line) = self.pending.pop_front() {
+                return Some(line);
+            }
+            let line = self.lines.next()?;
+            self.pending.extend(self.pipeline.apply(line.as_ref()));
+        }
+    }
+}
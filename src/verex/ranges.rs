@@ -0,0 +1,52 @@
+use std::error;
+use std::fmt;
+
+/// Options for `Verex::range_with()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RangeOptions {
+    /// Whether the case variants of the letters in the ranges are added, e.g. `A-Z` for `a-z`
+    pub fold_case: bool,
+    /// Whether ranges spanning the surrogates U+D800 to U+DFFF are rejected, as they silently skip them
+    pub reject_surrogates: bool
+}
+
+/// The errors that can occur when adding ranges with `Verex::range_with()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeError {
+    /// No ranges were given
+    Empty,
+    /// The start of the range is after its end
+    Reversed(char, char),
+    /// The range spans the surrogates, which are no characters and never match
+    Surrogates(char, char)
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RangeError::Empty => write!(f, "no ranges given"),
+            RangeError::Reversed(from, to) => write!(f, "range {:?}-{:?} starts after its end", from, to),
+            RangeError::Surrogates(from, to) => {
+                write!(f, "range U+{:04X}-U+{:04X} spans the surrogates U+D800-U+DFFF", from as u32, to as u32)
+            },
+        }
+    }
+}
+
+impl error::Error for RangeError {}
+
+/// Check that the ranges are well-formed
+pub(crate) fn check(ranges: &[(char, char)], options: RangeOptions) -> Result<(), RangeError> {
+    if ranges.is_empty() {
+        return Err(RangeError::Empty);
+    }
+    for &(from, to) in ranges {
+        if from > to {
+            return Err(RangeError::Reversed(from, to));
+        }
+        if options.reject_surrogates && (from as u32) < 0xD800 && (to as u32) > 0xDFFF {
+            return Err(RangeError::Surrogates(from, to));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::sync::RwLock;
+
+use regex::{Error, Regex};
+
+use super::Verex;
+
+/// The errors that can occur when registering or looking up patterns
+#[derive(Debug)]
+pub enum RegistryError {
+    /// A pattern with the name is already registered
+    Duplicate(String),
+    /// There is no pattern with the name
+    UnknownPattern(String),
+    /// The pattern with the name does not compile
+    Compile(String, Error)
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RegistryError::Duplicate(ref name) => write!(f, "pattern '{}' is already registered", name),
+            RegistryError::UnknownPattern(ref name) => write!(f, "no pattern named '{}' is registered", name),
+            RegistryError::Compile(ref name, ref error) => write!(f, "pattern '{}' does not compile: {}", name, error),
+        }
+    }
+}
+
+impl error::Error for RegistryError {}
+
+/// A source of named patterns, e.g. a plugin, that registers them all at once
+pub trait VerexProvider {
+    /// Register the patterns of this provider
+    fn provide(&self, registry: &PatternRegistry) -> Result<(), RegistryError>;
+}
+
+/// A thread-safe collection of named patterns that are compiled when first used
+#[derive(Debug, Default)]
+pub struct PatternRegistry {
+    patterns: RwLock<HashMap<String, Verex>>
+}
+
+impl PatternRegistry {
+    /// Create an empty registry
+    pub fn new() -> PatternRegistry {
+        PatternRegistry::default()
+    }
+
+    /// Return a copy of the pattern with the given name
+    pub fn get(& self, name: &str) -> Option<Verex> {
+        self.patterns.read().unwrap().get(name).cloned()
+    }
+
+    /// Return the names of all patterns in alphabetical order
+    pub fn names(& self) -> Vec<String> {
+        let mut names: Vec<String> = self.patterns.read().unwrap().keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Compile the pattern with the given name, or return it from the cache if it was compiled before
+    pub fn regex(& self, name: &str) -> Result<Regex, RegistryError> {
+        let verex = self.get(name).ok_or_else(|| RegistryError::UnknownPattern(name.to_owned()))?;
+        verex.compile().map_err(|error| RegistryError::Compile(name.to_owned(), error))
+    }
+
+    /// Add a pattern under a name that is not registered yet
+    pub fn register(& self, name: &str, verex: Verex) -> Result<(), RegistryError> {
+        let mut patterns = self.patterns.write().unwrap();
+        if patterns.contains_key(name) {
+            return Err(RegistryError::Duplicate(name.to_owned()));
+        }
+        patterns.insert(name.to_owned(), verex);
+        Ok(())
+    }
+
+    /// Let the provider register its patterns
+    pub fn register_provider(& self, provider: &dyn VerexProvider) -> Result<(), RegistryError> {
+        provider.provide(self)
+    }
+}
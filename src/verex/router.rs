@@ -0,0 +1,100 @@
+use regex::{Error, Regex, RegexSet};
+
+use super::Verex;
+
+/// How `VerexRouter::route()` chooses among several matching routes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoutePolicy {
+    /// The route added first wins
+    FirstMatch,
+    /// The route with the longest match wins, the one added first among equally long ones
+    LongestMatch
+}
+
+/// A route that matched a text in `VerexRouter::route()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteMatch<'r, 't, T: 'r> {
+    /// The value of the route
+    pub value: &'r T,
+    /// The index of the route in the order the routes were added
+    pub index: usize,
+    /// The byte offset where the match starts
+    pub start: usize,
+    /// The byte offset where the match ends
+    pub end: usize,
+    /// The named capturing groups of the pattern that took part in the match
+    pub params: Vec<(String, &'t str)>
+}
+
+impl<'r, 't, T> RouteMatch<'r, 't, T> {
+    /// Return the text captured by the named group
+    pub fn param(& self, name: &str) -> Option<&'t str> {
+        self.params.iter().find(|&(param, _)| param == name).map(|&(_, text)| text)
+    }
+}
+
+/// Maps patterns to values, e.g. paths to handlers, and finds the route for a text
+///
+/// All patterns are checked at once with a `RegexSet`, only the matching ones are searched individually.
+/// Patterns are not anchored automatically, use `start_of_line()` and `end_of_line()` to match whole texts.
+#[derive(Debug, Clone)]
+pub struct VerexRouter<T> {
+    policy: RoutePolicy,
+    set: RegexSet,
+    routes: Vec<(Regex, T)>
+}
+
+impl<T> VerexRouter<T> {
+    /// Create a router without routes
+    pub fn new(policy: RoutePolicy) -> VerexRouter<T> {
+        VerexRouter {
+            policy,
+            set: RegexSet::new(Vec::<&str>::new()).unwrap(),
+            routes: Vec::new()
+        }
+    }
+
+    /// Add a route, which is checked after the routes added before
+    pub fn add(&mut self, verex: &Verex, value: T) -> Result<&mut VerexRouter<T>, Error> {
+        let regex = verex.compile()?;
+        self.set = RegexSet::new(self.routes.iter().map(|(regex, _)| regex.as_str()).chain(Some(regex.as_str())))?;
+        self.routes.push((regex, value));
+        Ok(self)
+    }
+
+    /// Return the number of routes
+    pub fn len(& self) -> usize {
+        self.routes.len()
+    }
+
+    /// Whether there are no routes
+    pub fn is_empty(& self) -> bool {
+        self.routes.is_empty()
+    }
+
+    /// Find the route for the text according to the policy
+    pub fn route<'r, 't>(&'r self, text: &'t str) -> Option<RouteMatch<'r, 't, T>> {
+        let mut matches = self.route_all(text).into_iter();
+        match self.policy {
+            RoutePolicy::FirstMatch => matches.next(),
+            RoutePolicy::LongestMatch => matches.fold(None, |best: Option<RouteMatch<T>>, route| match best {
+                Some(best) if best.end - best.start >= route.end - route.start => Some(best),
+                _ => Some(route),
+            }),
+        }
+    }
+
+    /// Return all routes matching the text in the order they were added
+    pub fn route_all<'r, 't>(&'r self, text: &'t str) -> Vec<RouteMatch<'r, 't, T>> {
+        self.set.matches(text).into_iter().filter_map(|index| {
+            let (ref regex, ref value) = self.routes[index];
+            let captures = regex.captures(text)?;
+            let (start, end) = captures.pos(0)?;
+            let params = regex.capture_names().enumerate().filter_map(|(i, name)| {
+                let (start, end) = captures.pos(i)?;
+                name.map(|name| (name.to_owned(), &text[start..end]))
+            }).collect();
+            Some(RouteMatch { value, index, start, end, params })
+        }).collect()
+    }
+}
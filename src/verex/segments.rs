@@ -0,0 +1,105 @@
+use regex::Regex;
+
+/// A piece of a text split by `Verex::segments()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segment<'t> {
+    /// A part of the text that matched the expression
+    Matched(&'t str),
+    /// A part of the text between two matches
+    Unmatched(&'t str)
+}
+
+impl<'t> Segment<'t> {
+    /// Return the text of the segment regardless of whether it matched
+    pub fn as_str(&self) -> &'t str {
+        match *self {
+            Segment::Matched(text) => text,
+            Segment::Unmatched(text) => text,
+        }
+    }
+
+    /// Whether the segment is a match
+    pub fn is_matched(&self) -> bool {
+        match *self {
+            Segment::Matched(_) => true,
+            Segment::Unmatched(_) => false,
+        }
+    }
+}
+
+/// Iterator over the alternating matched and unmatched segments of a text
+///
+/// Empty matches are skipped, so concatenating all segments yields the original text.
+#[derive(Debug)]
+pub struct Segments<'t> {
+    regex: Regex,
+    text: &'t str,
+    last: usize,
+    search: usize,
+    pending: Option<(usize, usize)>,
+    done: bool
+}
+
+impl<'t> Segments<'t> {
+    /// Create the iterator from a compiled regex
+    pub(crate) fn new(regex: Regex, text: &'t str) -> Segments<'t> {
+        Segments {
+            regex,
+            text,
+            last: 0,
+            search: 0,
+            pending: None,
+            done: false
+        }
+    }
+
+    /// Find the next non-empty match at or after the search position
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        while self.search <= self.text.len() {
+            let (start, end) = self.regex.find_at(self.text, self.search)?;
+            if start != end {
+                self.search = end;
+                return Some((start, end));
+            }
+            // skip over the empty match without splitting a character
+            self.search = match self.text[start..].chars().next() {
+                Some(c) => start + c.len_utf8(),
+                None => return None,
+            };
+        }
+        None
+    }
+}
+
+impl<'t> Iterator for Segments<'t> {
+    type Item = Segment<'t>;
+
+    fn next(&mut self) -> Option<Segment<'t>> {
+        if let Some((start, end)) = self.pending.take() {
+            return Some(Segment::Matched(&self.text[start..end]));
+        }
+        if self.done {
+            return None;
+        }
+        match self.next_match() {
+            Some((start, end)) => {
+                let last = self.last;
+                self.last = end;
+                if start > last {
+                    self.pending = Some((start, end));
+                    Some(Segment::Unmatched(&self.text[last..start]))
+                } else {
+                    Some(Segment::Matched(&self.text[start..end]))
+                }
+            },
+            None => {
+                self.done = true;
+                if self.last < self.text.len() {
+                    Some(Segment::Unmatched(&self.text[self.last..]))
+                } else {
+                    None
+                }
+            },
+        }
+    }
+}
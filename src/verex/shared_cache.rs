@@ -0,0 +1,37 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use regex::{Error, Regex};
+
+/// The maximum number of regexes kept per thread, the cache is cleared when it is full
+const CAPACITY: usize = 256;
+
+thread_local! {
+    static CACHE: RefCell<HashMap<String, Regex>> = RefCell::new(HashMap::new());
+}
+
+/// Return the compiled regex of the source from the cache of the current thread, compiling it on a miss
+pub(crate) fn get_or_compile(source: &str) -> Result<Regex, Error> {
+    if let Some(regex) = CACHE.with(|cache| cache.borrow().get(source).cloned()) {
+        return Ok(regex);
+    }
+    let regex = Regex::new(source)?;
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.len() >= CAPACITY {
+            cache.clear();
+        }
+        cache.insert(source.to_owned(), regex.clone());
+    });
+    Ok(regex)
+}
+
+/// Remove all regexes compiled with `Verex::compile_shared()` from the cache of the current thread
+pub fn clear_shared_cache() {
+    CACHE.with(|cache| cache.borrow_mut().clear());
+}
+
+/// Return the number of regexes in the cache of the current thread
+pub fn shared_cache_len() -> usize {
+    CACHE.with(|cache| cache.borrow().len())
+}
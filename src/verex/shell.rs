@@ -0,0 +1,33 @@
+/// The shells `Verex::shell_quoted()` can quote the source for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    /// Bash and other POSIX shells like `sh`, `dash` and `zsh`
+    Bash,
+    /// The fish shell
+    Fish,
+    /// Windows PowerShell and PowerShell Core
+    PowerShell
+}
+
+/// Quote a string so the shell passes it on as a single argument without interpreting it
+pub fn quote(string: &str, shell: Shell) -> String {
+    let mut result = String::with_capacity(string.len() + 2);
+    result.push('\'');
+    for c in string.chars() {
+        match (shell, c) {
+            // single quotes can not be escaped inside of single quotes in POSIX shells
+            (Shell::Bash, '\'') => result.push_str(r"'\''"),
+            (Shell::Fish, '\'') => result.push_str(r"\'"),
+            (Shell::Fish, '\\') => result.push_str(r"\\"),
+            // PowerShell also treats typographic single quotes as quotes
+            (Shell::PowerShell, '\'') | (Shell::PowerShell, '\u{2018}') | (Shell::PowerShell, '\u{2019}')
+            | (Shell::PowerShell, '\u{201A}') | (Shell::PowerShell, '\u{201B}') => {
+                result.push(c);
+                result.push(c);
+            },
+            _ => result.push(c),
+        }
+    }
+    result.push('\'');
+    result
+}
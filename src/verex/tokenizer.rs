@@ -0,0 +1,146 @@
+use std::error;
+use std::fmt;
+
+use regex::{Error, Regex};
+
+use super::Verex;
+
+/// A token found by `Tokenizer::tokenize()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'t, K> {
+    /// The kind of the rule that matched
+    pub kind: K,
+    /// The text of the token
+    pub text: &'t str,
+    /// The byte offset where the token starts
+    pub start: usize,
+    /// The byte offset where the token ends
+    pub end: usize
+}
+
+/// The error returned when no rule matches at a position of the text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenizeError {
+    /// The byte offset where no rule matched
+    pub offset: usize
+}
+
+impl fmt::Display for TokenizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no token matches at offset {}", self.offset)
+    }
+}
+
+impl error::Error for TokenizeError {}
+
+/// Splits a text into tokens of different kinds, e.g. for a lexer
+///
+/// At every position the rule with the longest match wins, the one added first among equally long
+/// ones. Text matched by a skip rule, like whitespace or comments, is consumed between the tokens
+/// without being emitted. Rules are matched at the current position only, so `^` and `\b` see the
+/// rest of the text as if it started there; empty matches are ignored.
+#[derive(Debug, Clone)]
+pub struct Tokenizer<K> {
+    rules: Vec<(K, Regex)>,
+    skip: Vec<Regex>
+}
+
+/// Compile a `Verex` so it only matches at the start of the text
+fn anchored(verex: &Verex) -> Result<Regex, Error> {
+    Regex::new(format!("^{}", verex.source()).as_ref())
+}
+
+impl<K: Clone> Tokenizer<K> {
+    /// Create a tokenizer without rules
+    pub fn new() -> Tokenizer<K> {
+        Tokenizer {
+            rules: Vec::new(),
+            skip: Vec::new()
+        }
+    }
+
+    /// Add a rule for tokens of the given kind
+    pub fn rule(&mut self, kind: K, verex: &Verex) -> Result<&mut Tokenizer<K>, Error> {
+        self.rules.push((kind, anchored(verex)?));
+        Ok(self)
+    }
+
+    /// Add a rule for text that is skipped between tokens, e.g. whitespace or comments
+    pub fn skip(&mut self, verex: &Verex) -> Result<&mut Tokenizer<K>, Error> {
+        self.skip.push(anchored(verex)?);
+        Ok(self)
+    }
+
+    /// Split the text into tokens, stopping after the first position no rule matches
+    pub fn tokenize<'k, 't>(&'k self, text: &'t str) -> Tokens<'k, 't, K> {
+        Tokens {
+            tokenizer: self,
+            text,
+            position: 0,
+            done: false
+        }
+    }
+
+    /// Return the end of the longest non-empty match of the regexes at the position
+    fn longest<'r, I>(regexes: I, text: &str, position: usize) -> Option<(usize, usize)> where I: Iterator<Item = &'r Regex> {
+        let rest = &text[position..];
+        let mut best: Option<(usize, usize)> = None;
+        for (index, regex) in regexes.enumerate() {
+            if let Some((_, end)) = regex.find(rest) {
+                if end > 0 && best.map(|(_, best_end)| end > best_end) != Some(false) {
+                    best = Some((index, end));
+                }
+            }
+        }
+        best.map(|(index, end)| (index, position + end))
+    }
+}
+
+impl<K: Clone> Default for Tokenizer<K> {
+    fn default() -> Tokenizer<K> {
+        Tokenizer::new()
+    }
+}
+
+/// Iterator over the tokens of `Tokenizer::tokenize()`
+#[derive(Debug)]
+pub struct Tokens<'k, 't, K: 'k> {
+    tokenizer: &'k Tokenizer<K>,
+    text: &'t str,
+    position: usize,
+    done: bool
+}
+
+impl<'k, 't, K: Clone> Iterator for Tokens<'k, 't, K> {
+    type Item = Result<Token<'t, K>, TokenizeError>;
+
+    fn next(&mut self) -> Option<Result<Token<'t, K>, TokenizeError>> {
+        if self.done {
+            return None;
+        }
+        while let Some((_, end)) = Tokenizer::<K>::longest(self.tokenizer.skip.iter(), self.text, self.position) {
+            self.position = end;
+        }
+        if self.position == self.text.len() {
+            self.done = true;
+            return None;
+        }
+        let rules = self.tokenizer.rules.iter().map(|(_, regex)| regex);
+        match Tokenizer::<K>::longest(rules, self.text, self.position) {
+            Some((index, end)) => {
+                let token = Token {
+                    kind: self.tokenizer.rules[index].0.clone(),
+                    text: &self.text[self.position..end],
+                    start: self.position,
+                    end
+                };
+                self.position = end;
+                Some(Ok(token))
+            },
+            None => {
+                self.done = true;
+                Some(Err(TokenizeError { offset: self.position }))
+            },
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::fmt;
+
+use regex::{Error, Regex};
+
+use super::Verex;
+
+/// A constraint of a `Validator` that a text broke
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// A required pattern does not match anywhere in the text
+    Missing(String),
+    /// A forbidden pattern matches the text at the given byte offsets
+    Forbidden {
+        /// The name of the constraint
+        name: String,
+        /// The byte offset where the first match starts
+        start: usize,
+        /// The byte offset where the first match ends
+        end: usize
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Violation::Missing(ref name) => write!(f, "missing {}", name),
+            Violation::Forbidden { ref name, start, end } => write!(f, "forbidden {} at {}..{}", name, start, end),
+        }
+    }
+}
+
+/// Checks a text against several named patterns it must or must not contain, e.g. "a password must
+/// contain a digit but must not contain whitespace"
+#[derive(Debug, Clone, Default)]
+pub struct Validator {
+    required: Vec<(String, Regex)>,
+    forbidden: Vec<(String, Regex)>
+}
+
+impl Validator {
+    /// Create a validator without constraints, which accepts every text
+    pub fn new() -> Validator {
+        Validator::default()
+    }
+
+    /// Add a constraint that the pattern matches somewhere in the text
+    pub fn require(&mut self, name: &str, verex: &Verex) -> Result<&mut Validator, Error> {
+        self.required.push((name.to_owned(), verex.compile()?));
+        Ok(self)
+    }
+
+    /// Add a constraint that the pattern matches nowhere in the text
+    pub fn forbid(&mut self, name: &str, verex: &Verex) -> Result<&mut Validator, Error> {
+        self.forbidden.push((name.to_owned(), verex.compile()?));
+        Ok(self)
+    }
+
+    /// Check all constraints and return the broken ones, the required before the forbidden ones
+    pub fn validate(& self, text: &str) -> Result<(), Vec<Violation>> {
+        let missing = self.required.iter()
+                                   .filter(|(_, regex)| !regex.is_match(text))
+                                   .map(|(name, _)| Violation::Missing(name.clone()));
+        let forbidden = self.forbidden.iter().filter_map(|(name, regex)| {
+            regex.find(text).map(|(start, end)| Violation::Forbidden { name: name.clone(), start, end })
+        });
+        let violations: Vec<Violation> = missing.chain(forbidden).collect();
+        if violations.is_empty() {
+            Ok(())
+        }
+        else {
+            Err(violations)
+        }
+    }
+
+    /// Whether the text meets all constraints
+    pub fn is_valid(& self, text: &str) -> bool {
+        self.required.iter().all(|(_, regex)| regex.is_match(text))
+            && !self.forbidden.iter().any(|(_, regex)| regex.is_match(text))
+    }
+}
@@ -0,0 +1,34 @@
+use std::ffi::OsStr;
+
+use clap::{Arg, Command};
+use clap::builder::TypedValueParser;
+use clap::error::ErrorKind;
+use regex::Regex;
+
+/// A clap value parser accepting arguments that match a `Verex`, created by `Verex::value_parser()`
+#[derive(Debug, Clone)]
+pub struct VerexValueParser {
+    regex: Regex,
+    description: String
+}
+
+impl VerexValueParser {
+    /// Create the parser from the compiled expression and the description shown for invalid values
+    pub(crate) fn new(regex: Regex, description: String) -> VerexValueParser {
+        VerexValueParser { regex, description }
+    }
+}
+
+impl TypedValueParser for VerexValueParser {
+    type Value = String;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<String, clap::Error> {
+        let value = value.to_str().ok_or_else(|| clap::Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        if self.regex.is_match(value) {
+            return Ok(value.to_owned());
+        }
+        let arg = arg.map(|arg| arg.to_string()).unwrap_or_else(|| "...".to_owned());
+        let message = format!("invalid value '{}' for '{}': does not match {}\n", value, arg, self.description);
+        Err(clap::Error::raw(ErrorKind::ValueValidation, message).with_cmd(cmd))
+    }
+}
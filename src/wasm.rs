@@ -0,0 +1,191 @@
+//! Bindings for using the builder from JavaScript, e.g. in a web playground
+//!
+//! Build the crate for `wasm32-unknown-unknown` with only the `wasm` feature enabled, the other
+//! optional features are not needed in the browser, and the `bake` module with its filesystem and
+//! environment access is not compiled for that target. The `Verex` class of the bindings mirrors the
+//! builder methods, each of them consumes the object and returns a new one, so calls can be chained:
+//!
+//! ```js
+//! const verex = new Verex().startOfLine().find("http").maybe("s").find("://").endOfLine();
+//! verex.isMatch("https://"); // true
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+use Verex;
+
+/// A `Verex` wrapped for JavaScript
+#[wasm_bindgen(js_name = Verex)]
+#[derive(Debug, Clone, Default)]
+pub struct WasmVerex {
+    verex: Verex
+}
+
+#[wasm_bindgen(js_class = Verex)]
+impl WasmVerex {
+    /// Create an empty expression
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmVerex {
+        WasmVerex { verex: Verex::new() }
+    }
+
+    /// Build an expression from the text DSL of `Verex::parse_dsl()`
+    #[wasm_bindgen(js_name = parseDsl)]
+    pub fn parse_dsl(dsl: &str) -> Result<WasmVerex, JsError> {
+        Verex::parse_dsl(dsl).map(WasmVerex::from).map_err(|error| JsError::new(error.to_string().as_ref()))
+    }
+
+    /// Return the regex source of the expression
+    pub fn source(& self) -> String {
+        self.verex.source().to_owned()
+    }
+
+    /// Whether the expression matches somewhere in the text
+    #[wasm_bindgen(js_name = isMatch)]
+    pub fn is_match(& self, text: &str) -> Result<bool, JsError> {
+        self.verex.compile().map(|regex| regex.is_match(text)).map_err(|error| JsError::new(error.to_string().as_ref()))
+    }
+
+    /// Return all non-overlapping matches in the text
+    #[wasm_bindgen(js_name = findAll)]
+    pub fn find_all(& self, text: &str) -> Result<Vec<String>, JsError> {
+        let regex = self.verex.compile().map_err(|error| JsError::new(error.to_string().as_ref()))?;
+        Ok(regex.find_iter(text).map(|(start, end)| text[start..end].to_owned()).collect())
+    }
+
+    /// See `Verex::any()`
+    pub fn any(mut self, chars: &str) -> WasmVerex {
+        self.verex.any(chars);
+        self
+    }
+
+    /// See `Verex::anything()`
+    pub fn anything(mut self) -> WasmVerex {
+        self.verex.anything();
+        self
+    }
+
+    /// See `Verex::anything_but()`
+    #[wasm_bindgen(js_name = anythingBut)]
+    pub fn anything_but(mut self, chars: &str) -> WasmVerex {
+        self.verex.anything_but(chars);
+        self
+    }
+
+    /// See `Verex::capture()`
+    pub fn capture(mut self, value: &str) -> WasmVerex {
+        self.verex.capture(value);
+        self
+    }
+
+    /// See `Verex::digit()`
+    pub fn digit(mut self) -> WasmVerex {
+        self.verex.digit();
+        self
+    }
+
+    /// See `Verex::end_of_line()`
+    #[wasm_bindgen(js_name = endOfLine)]
+    pub fn end_of_line(mut self) -> WasmVerex {
+        self.verex.end_of_line();
+        self
+    }
+
+    /// See `Verex::find()`
+    pub fn find(mut self, value: &str) -> WasmVerex {
+        self.verex.find(value);
+        self
+    }
+
+    /// See `Verex::line_break()`
+    #[wasm_bindgen(js_name = lineBreak)]
+    pub fn line_break(mut self) -> WasmVerex {
+        self.verex.line_break();
+        self
+    }
+
+    /// See `Verex::maybe()`
+    pub fn maybe(mut self, value: &str) -> WasmVerex {
+        self.verex.maybe(value);
+        self
+    }
+
+    /// See `Verex::multi_line()`
+    #[wasm_bindgen(js_name = multiLine)]
+    pub fn multi_line(mut self, enable: bool) -> WasmVerex {
+        self.verex.multi_line(enable);
+        self
+    }
+
+    /// See `Verex::or_find()`
+    #[wasm_bindgen(js_name = orFind)]
+    pub fn or_find(mut self, value: &str) -> WasmVerex {
+        self.verex.or_find(value);
+        self
+    }
+
+    /// See `Verex::repeat_n_to_m()`
+    #[wasm_bindgen(js_name = repeatNToM)]
+    pub fn repeat_n_to_m(mut self, n: u32, m: u32) -> WasmVerex {
+        self.verex.repeat_n_to_m(n, m);
+        self
+    }
+
+    /// See `Verex::repeat_once_or_more()`
+    #[wasm_bindgen(js_name = repeatOnceOrMore)]
+    pub fn repeat_once_or_more(mut self) -> WasmVerex {
+        self.verex.repeat_once_or_more();
+        self
+    }
+
+    /// See `Verex::something()`
+    pub fn something(mut self) -> WasmVerex {
+        self.verex.something();
+        self
+    }
+
+    /// See `Verex::something_but()`
+    #[wasm_bindgen(js_name = somethingBut)]
+    pub fn something_but(mut self, chars: &str) -> WasmVerex {
+        self.verex.something_but(chars);
+        self
+    }
+
+    /// See `Verex::start_of_line()`
+    #[wasm_bindgen(js_name = startOfLine)]
+    pub fn start_of_line(mut self) -> WasmVerex {
+        self.verex.start_of_line();
+        self
+    }
+
+    /// See `Verex::tab()`
+    pub fn tab(mut self) -> WasmVerex {
+        self.verex.tab();
+        self
+    }
+
+    /// See `Verex::with_any_case()`
+    #[wasm_bindgen(js_name = withAnyCase)]
+    pub fn with_any_case(mut self, enable: bool) -> WasmVerex {
+        self.verex.with_any_case(enable);
+        self
+    }
+
+    /// See `Verex::word()`
+    pub fn word(mut self) -> WasmVerex {
+        self.verex.word();
+        self
+    }
+}
+
+impl From<Verex> for WasmVerex {
+    fn from(verex: Verex) -> WasmVerex {
+        WasmVerex { verex }
+    }
+}
+
+impl From<WasmVerex> for Verex {
+    fn from(wasm: WasmVerex) -> Verex {
+        wasm.verex
+    }
+}